@@ -0,0 +1,365 @@
+//! Benchmarks for pallet_assets.
+
+use super::*;
+use crate::Pallet as Assets;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{sp_runtime::traits::Bounded, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+const MIN_BALANCE: u128 = 1;
+const AMOUNT: u128 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
+/// Create an asset owned by `owner` the same way users do, so that its deposit is reserved.
+fn create_asset<T: Config>(owner: &T::AccountId) -> AssetId {
+	let asset_id = Assets::<T>::nonce();
+	Assets::<T>::create(RawOrigin::Signed(owner.clone()).into(), MIN_BALANCE)
+		.expect("owner is funded");
+	asset_id
+}
+
+fn bounded_bytes<T: Config>(len: u32) -> BoundedVec<u8, T::MaxLength> {
+	vec![0u8; len as usize].try_into().expect("len is at most MaxLength")
+}
+
+benchmarks! {
+	create {
+		let caller = funded_caller::<T>();
+		let asset_id = Assets::<T>::nonce();
+	}: _(RawOrigin::Signed(caller.clone()), MIN_BALANCE)
+	verify {
+		assert_last_event::<T>(Event::Created { owner: caller, asset_id }.into());
+	}
+
+	force_create {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset_id = Assets::<T>::nonce();
+	}: _(RawOrigin::Root, asset_id, owner.clone(), MIN_BALANCE)
+	verify {
+		assert_last_event::<T>(Event::ForceCreated { owner, asset_id }.into());
+	}
+
+	set_metadata {
+		let n in 0 .. T::MaxLength::get();
+		let s in 0 .. T::MaxLength::get();
+
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let name = bounded_bytes::<T>(n);
+		let symbol = bounded_bytes::<T>(s);
+	}: _(RawOrigin::Signed(caller), asset_id, name.clone(), symbol.clone(), 12)
+	verify {
+		assert_last_event::<T>(Event::MetadataSet {
+			asset_id,
+			name,
+			symbol,
+			decimals: 12,
+			is_frozen: false,
+		}.into());
+	}
+
+	clear_metadata {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let bytes = bounded_bytes::<T>(T::MaxLength::get());
+		Assets::<T>::set_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			bytes.clone(),
+			bytes,
+			12,
+		)?;
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T>(Event::MetadataCleared { asset_id }.into());
+	}
+
+	force_set_metadata {
+		let n in 0 .. T::MaxLength::get();
+		let s in 0 .. T::MaxLength::get();
+
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let name = bounded_bytes::<T>(n);
+		let symbol = bounded_bytes::<T>(s);
+	}: _(RawOrigin::Root, asset_id, name.clone(), symbol.clone(), 12, true)
+	verify {
+		assert_last_event::<T>(Event::MetadataSet {
+			asset_id,
+			name,
+			symbol,
+			decimals: 12,
+			is_frozen: true,
+		}.into());
+	}
+
+	mint {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let to: T::AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(caller), asset_id, AMOUNT, to.clone())
+	verify {
+		assert_last_event::<T>(Event::Minted { asset_id, owner: to, total_supply: AMOUNT }.into());
+	}
+
+	burn {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		Assets::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			AMOUNT,
+			caller.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, AMOUNT)
+	verify {
+		assert_last_event::<T>(Event::Burned { asset_id, owner: caller, total_supply: 0 }.into());
+	}
+
+	transfer {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		Assets::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			AMOUNT,
+			caller.clone(),
+		)?;
+		let to: T::AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, AMOUNT / 2, to.clone())
+	verify {
+		assert_last_event::<T>(Event::Transferred {
+			asset_id,
+			from: caller,
+			to,
+			amount: AMOUNT / 2,
+		}.into());
+	}
+
+	transfer_batch {
+		let b in 1 .. T::MaxBatchSize::get();
+
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		Assets::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			AMOUNT * b as u128 + MIN_BALANCE,
+			caller.clone(),
+		)?;
+		let transfers: Vec<(T::AccountId, u128)> =
+			(0 .. b).map(|i| (account("to", i, SEED), AMOUNT)).collect();
+		let transfers: BoundedVec<_, T::MaxBatchSize> =
+			transfers.try_into().expect("b is at most MaxBatchSize");
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, transfers)
+	verify {
+		assert_last_event::<T>(Event::BatchTransferred {
+			asset_id,
+			from: caller,
+			recipients: b,
+			total: AMOUNT * b as u128,
+		}.into());
+	}
+
+	approve_transfer {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, delegate.clone(), AMOUNT)
+	verify {
+		assert_last_event::<T>(Event::ApprovedTransfer {
+			asset_id,
+			owner: caller,
+			delegate,
+			amount: AMOUNT,
+		}.into());
+	}
+
+	cancel_approval {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		Assets::<T>::approve_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			delegate.clone(),
+			AMOUNT,
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, delegate.clone())
+	verify {
+		assert_last_event::<T>(Event::ApprovalCancelled {
+			asset_id,
+			owner: caller,
+			delegate,
+		}.into());
+	}
+
+	transfer_approved {
+		let owner = funded_account::<T>("owner", 0);
+		let asset_id = create_asset::<T>(&owner);
+		Assets::<T>::mint(
+			RawOrigin::Signed(owner.clone()).into(),
+			asset_id,
+			AMOUNT,
+			owner.clone(),
+		)?;
+		let delegate = funded_caller::<T>();
+		Assets::<T>::approve_transfer(
+			RawOrigin::Signed(owner.clone()).into(),
+			asset_id,
+			delegate.clone(),
+			AMOUNT,
+		)?;
+		let to: T::AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(delegate), asset_id, owner.clone(), to.clone(), AMOUNT / 2)
+	verify {
+		assert_last_event::<T>(Event::Transferred {
+			asset_id,
+			from: owner,
+			to,
+			amount: AMOUNT / 2,
+		}.into());
+	}
+
+	freeze_asset {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T>(Event::AssetFrozen { asset_id }.into());
+	}
+
+	thaw_asset {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		Assets::<T>::freeze_asset(RawOrigin::Signed(caller.clone()).into(), asset_id)?;
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T>(Event::AssetThawed { asset_id }.into());
+	}
+
+	freeze_account {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let who: T::AccountId = account("who", 0, SEED);
+	}: _(RawOrigin::Signed(caller), asset_id, who.clone())
+	verify {
+		assert_last_event::<T>(Event::AccountFrozen { asset_id, who }.into());
+	}
+
+	thaw_account {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let who: T::AccountId = account("who", 0, SEED);
+		Assets::<T>::freeze_account(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			who.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller), asset_id, who.clone())
+	verify {
+		assert_last_event::<T>(Event::AccountThawed { asset_id, who }.into());
+	}
+
+	set_team {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let issuer: T::AccountId = account("issuer", 0, SEED);
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let freezer: T::AccountId = account("freezer", 0, SEED);
+	}: _(RawOrigin::Signed(caller), asset_id, issuer.clone(), admin.clone(), freezer.clone())
+	verify {
+		assert_last_event::<T>(Event::TeamChanged { asset_id, issuer, admin, freezer }.into());
+	}
+
+	transfer_ownership {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let bytes = bounded_bytes::<T>(T::MaxLength::get());
+		Assets::<T>::set_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			bytes.clone(),
+			bytes,
+			12,
+		)?;
+		// The deposits can only be repatriated to an existing account.
+		let owner = funded_account::<T>("owner", 0);
+	}: _(RawOrigin::Signed(caller), asset_id, owner.clone())
+	verify {
+		assert_last_event::<T>(Event::OwnerChanged { asset_id, owner }.into());
+	}
+
+	start_destroy {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T>(Event::DestructionStarted { asset_id }.into());
+	}
+
+	destroy_accounts {
+		let c in 1 .. 1_000;
+
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		for i in 0 .. c {
+			Assets::<T>::mint(
+				RawOrigin::Signed(caller.clone()).into(),
+				asset_id,
+				AMOUNT,
+				account("holder", i, SEED),
+			)?;
+		}
+		Assets::<T>::start_destroy(RawOrigin::Signed(caller.clone()).into(), asset_id)?;
+	}: _(RawOrigin::Signed(caller), asset_id, c)
+	verify {
+		assert_last_event::<T>(Event::AccountsDestroyed {
+			asset_id,
+			accounts_destroyed: c,
+			accounts_remaining: 0,
+			freezes_destroyed: 0,
+			approvals_destroyed: 0,
+		}.into());
+	}
+
+	finish_destroy {
+		let caller = funded_caller::<T>();
+		let asset_id = create_asset::<T>(&caller);
+		let bytes = bounded_bytes::<T>(T::MaxLength::get());
+		Assets::<T>::set_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			bytes.clone(),
+			bytes,
+			12,
+		)?;
+		Assets::<T>::start_destroy(RawOrigin::Signed(caller.clone()).into(), asset_id)?;
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_last_event::<T>(Event::Destroyed { asset_id }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Assets,
+		crate::tests::mock::new_test_ext(),
+		crate::tests::mock::TestRuntime,
+	);
+}
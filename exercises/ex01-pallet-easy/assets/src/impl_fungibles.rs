@@ -0,0 +1,134 @@
+//! The `fungibles` traits, on top of `Asset` and `Account`. Minimum balances and frozen accounts
+//! are enforced as for the calls, and only live assets can be minted, burned or transferred.
+
+use super::*;
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_runtime::DispatchError,
+	traits::tokens::{fungibles, DepositConsequence, WithdrawConsequence},
+};
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = AssetId;
+	type Balance = u128;
+
+	fn total_issuance(asset: AssetId) -> u128 {
+		Self::asset(asset).map_or(0, |details| details.supply)
+	}
+
+	fn minimum_balance(asset: AssetId) -> u128 {
+		Self::asset(asset).map_or(0, |details| details.min_balance)
+	}
+
+	fn balance(asset: AssetId, who: &T::AccountId) -> u128 {
+		Self::account(asset, who)
+	}
+
+	fn reducible_balance(asset: AssetId, who: &T::AccountId, keep_alive: bool) -> u128 {
+		let details = match Self::asset(asset) {
+			Some(details) => details,
+			None => return 0,
+		};
+		if details.status != AssetStatus::Live
+			|| Self::ensure_not_frozen(asset, who.clone()).is_err()
+		{
+			return 0;
+		}
+
+		let balance = Self::account(asset, who);
+		if keep_alive {
+			balance.saturating_sub(details.min_balance)
+		} else {
+			balance
+		}
+	}
+
+	fn can_deposit(
+		asset: AssetId,
+		who: &T::AccountId,
+		amount: u128,
+		mint: bool,
+	) -> DepositConsequence {
+		let details = match Self::asset(asset) {
+			Some(details) if details.status == AssetStatus::Live => details,
+			_ => return DepositConsequence::UnknownAsset,
+		};
+		if mint && details.supply.checked_add(amount).is_none() {
+			return DepositConsequence::Overflow;
+		}
+
+		match Self::account(asset, who).checked_add(amount) {
+			None => DepositConsequence::Overflow,
+			Some(balance) if balance < details.min_balance => DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(asset: AssetId, who: &T::AccountId, amount: u128) -> WithdrawConsequence<u128> {
+		let details = match Self::asset(asset) {
+			Some(details) if details.status == AssetStatus::Live => details,
+			_ => return WithdrawConsequence::UnknownAsset,
+		};
+		if details.supply.checked_sub(amount).is_none() {
+			return WithdrawConsequence::Underflow;
+		}
+		if Self::ensure_not_frozen(asset, who.clone()).is_err() {
+			return WithdrawConsequence::Frozen;
+		}
+
+		match Self::account(asset, who).checked_sub(amount) {
+			None => WithdrawConsequence::NoFunds,
+			// Dust is never left behind, the withdrawal would be rejected.
+			Some(rest) if rest != 0 && rest < details.min_balance => WithdrawConsequence::WouldDie,
+			Some(_) => WithdrawConsequence::Success,
+		}
+	}
+}
+
+impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(asset: AssetId, who: &T::AccountId, amount: u128) -> DispatchResult {
+		Self::do_mint(asset, who.clone(), amount)?;
+		Ok(())
+	}
+
+	fn burn_from(asset: AssetId, who: &T::AccountId, amount: u128) -> Result<u128, DispatchError> {
+		Self::do_burn(asset, who.clone(), amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> fungibles::Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		asset: AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: u128,
+		keep_alive: bool,
+	) -> Result<u128, DispatchError> {
+		if keep_alive {
+			let balance = Self::account(asset, source);
+			let min_balance = Self::asset(asset).map_or(0, |details| details.min_balance);
+			ensure!(
+				balance.saturating_sub(amount) >= min_balance,
+				Error::<T>::BalanceLow
+			);
+		}
+
+		Self::do_transfer(asset, source.clone(), dest.clone(), amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> fungibles::Create<T::AccountId> for Pallet<T> {
+	/// Assets created this way do not require any deposit. There is no notion of sufficient asset
+	/// in this pallet, so `is_sufficient` is ignored.
+	fn create(
+		id: AssetId,
+		admin: T::AccountId,
+		_is_sufficient: bool,
+		min_balance: u128,
+	) -> DispatchResult {
+		Self::do_force_create(id, admin, min_balance)?;
+		Ok(())
+	}
+}
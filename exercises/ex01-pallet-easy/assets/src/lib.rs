@@ -2,10 +2,28 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod impl_fungibles;
+pub mod migrations;
+pub mod payment;
+pub mod runtime_api;
 pub mod types;
+pub mod weights;
 
-use frame_support::ensure;
+use frame_support::{
+	ensure,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{tokens::BalanceConversion, BalanceStatus, Currency, ReservableCurrency},
+	transactional,
+};
+pub use payment::{ChargeAssetTxPayment, HandleAssetFee};
+use sp_std::vec::Vec;
 use types::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 #[cfg(test)]
 mod tests;
@@ -14,9 +32,7 @@ mod tests;
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_support::sp_runtime;
 	use frame_system::pallet_prelude::*;
-	use std::cmp;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
@@ -24,12 +40,45 @@ pub mod pallet {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
+		/// The currency used to reserve asset and metadata deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved when creating an asset.
+		#[pallet::constant]
+		type AssetDeposit: Get<BalanceOf<Self>>;
+
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
+
+		/// The base deposit reserved when setting the metadata of an asset.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// The additional deposit reserved per byte of name and symbol.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum number of recipients in a single `transfer_batch`.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Converts a fee in the native currency into an amount of an asset, for transactions
+		/// paying their fees with `ChargeAssetTxPayment`.
+		type FeeConversion: BalanceConversion<BalanceOf<Self>, AssetId, u128>;
+
+		/// Receives the fees paid with `ChargeAssetTxPayment`. With `()`, they are burned.
+		type OnAssetFee: HandleAssetFee;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	pub(super) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// The pallet's runtime storage items.
@@ -40,7 +89,7 @@ pub mod pallet {
 	#[pallet::getter(fn asset)]
 	/// Details of an asset.
 	pub(super) type Asset<T: Config> =
-		StorageMap<_, Blake2_128Concat, AssetId, AssetDetails<T::AccountId>>;
+		StorageMap<_, Blake2_128Concat, AssetId, AssetDetails<T::AccountId, BalanceOf<T>>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn account)]
@@ -55,17 +104,55 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// The assets held by a specific account.
+	pub(super) type AccountAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn metadata)]
 	/// Details of an asset.
 	pub(super) type Metadata<T: Config> =
-		StorageMap<_, Blake2_128Concat, AssetId, types::AssetMetadata<T::MaxLength>>;
+		StorageMap<_, Blake2_128Concat, AssetId, AssetMetadata<BalanceOf<T>, T::MaxLength>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn nonce)]
 	/// Nonce for id of the next created asset.
 	pub(super) type Nonce<T: Config> = StorageValue<_, AssetId, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_account)]
+	/// Whether a specific account is frozen for a specific asset.
+	pub(super) type FrozenAccount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	/// Amount of an asset that a delegate is allowed to transfer on behalf of an owner.
+	pub(super) type Approvals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // owner
+			NMapKey<Blake2_128Concat, T::AccountId>, // delegate
+		),
+		u128,
+	>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -76,12 +163,21 @@ pub mod pallet {
 			owner: T::AccountId,
 			asset_id: AssetId,
 		},
+		/// New asset created by root.
+		ForceCreated {
+			owner: T::AccountId,
+			asset_id: AssetId,
+		},
 		/// New metadata has been set for an asset.
 		MetadataSet {
 			asset_id: AssetId,
 			name: BoundedVec<u8, T::MaxLength>,
 			symbol: BoundedVec<u8, T::MaxLength>,
+			decimals: u8,
+			is_frozen: bool,
 		},
+		/// The metadata of an asset has been cleared.
+		MetadataCleared { asset_id: AssetId },
 		/// Some assets have been minted.
 		Minted {
 			asset_id: AssetId,
@@ -101,6 +197,71 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: u128,
 		},
+		/// A batch of transfers has been executed.
+		BatchTransferred {
+			asset_id: AssetId,
+			from: T::AccountId,
+			recipients: u32,
+			total: u128,
+		},
+		/// An owner allowed a delegate to transfer some of its assets.
+		ApprovedTransfer {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			amount: u128,
+		},
+		/// An approval has been cancelled by its owner.
+		ApprovalCancelled {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+		},
+		/// An asset has been frozen, no transfer nor burn can happen anymore.
+		AssetFrozen { asset_id: AssetId },
+		/// A frozen asset has been thawed.
+		AssetThawed { asset_id: AssetId },
+		/// An account has been frozen for an asset.
+		AccountFrozen {
+			asset_id: AssetId,
+			who: T::AccountId,
+		},
+		/// A frozen account has been thawed for an asset.
+		AccountThawed {
+			asset_id: AssetId,
+			who: T::AccountId,
+		},
+		/// The management team of an asset has changed.
+		TeamChanged {
+			asset_id: AssetId,
+			issuer: T::AccountId,
+			admin: T::AccountId,
+			freezer: T::AccountId,
+		},
+		/// The owner of an asset has changed.
+		OwnerChanged {
+			asset_id: AssetId,
+			owner: T::AccountId,
+		},
+		/// The destruction of an asset has started.
+		DestructionStarted { asset_id: AssetId },
+		/// Some accounts, frozen accounts and approvals of an asset being destroyed have been
+		/// removed. `accounts_remaining` only counts the accounts holding a balance.
+		AccountsDestroyed {
+			asset_id: AssetId,
+			accounts_destroyed: u32,
+			accounts_remaining: u32,
+			freezes_destroyed: u32,
+			approvals_destroyed: u32,
+		},
+		/// An asset has been fully destroyed.
+		Destroyed { asset_id: AssetId },
+		/// A transaction fee has been paid in an asset.
+		AssetTxFeePaid {
+			who: T::AccountId,
+			asset_id: AssetId,
+			actual_fee: u128,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -110,6 +271,28 @@ pub mod pallet {
 		UnknownAssetId,
 		/// The signing account has no permission to do the operation.
 		NoPermission,
+		/// No approval exists for this delegate, or it is lower than the requested amount.
+		Unapproved,
+		/// The account balance is too low for the operation.
+		InsufficientBalance,
+		/// The operation would overflow a balance or the total supply.
+		Overflow,
+		/// The amount must be greater than zero.
+		ZeroAmount,
+		/// The asset or the account is frozen.
+		Frozen,
+		/// The minimum balance of an asset must be greater than zero.
+		MinBalanceZero,
+		/// The resulting non-zero balance would be lower than the asset minimum balance.
+		BalanceLow,
+		/// The asset is being destroyed.
+		AssetNotLive,
+		/// The asset is not being destroyed.
+		IncorrectStatus,
+		/// The asset still has accounts, frozen accounts or approvals to destroy.
+		InUse,
+		/// The asset ID is already taken.
+		AlreadyExists,
 	}
 
 	// Dispatchable functions allow users to interact with the pallet and invoke state changes.
@@ -117,12 +300,16 @@ pub mod pallet {
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(0)]
-		pub fn create(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create(origin: OriginFor<T>, min_balance: u128) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			ensure!(min_balance > 0, Error::<T>::MinBalanceZero);
+
+			let deposit = T::AssetDeposit::get();
+			T::Currency::reserve(&origin, deposit)?;
 
 			let id = Self::nonce();
-			let details = AssetDetails::new(origin.clone());
+			let details = AssetDetails::new(origin.clone(), deposit, min_balance);
 
 			Asset::<T>::insert(id, details);
 			Nonce::<T>::set(id.saturating_add(1));
@@ -135,34 +322,122 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		/// Create an asset with a chosen ID without reserving any deposit. Only callable by root.
+		#[pallet::weight(T::WeightInfo::force_create())]
+		pub fn force_create(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			owner: T::AccountId,
+			min_balance: u128,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::do_force_create(asset_id, owner, min_balance)?;
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_metadata(name.len() as u32, symbol.len() as u32))]
 		pub fn set_metadata(
 			origin: OriginFor<T>,
 			asset_id: AssetId,
 			name: BoundedVec<u8, T::MaxLength>,
 			symbol: BoundedVec<u8, T::MaxLength>,
+			decimals: u8,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-			Self::ensure_is_owner(asset_id, origin)?;
-			let asset_metadata = AssetMetadata {
-				name: name.clone(),
-				symbol: symbol.clone(),
-			};
-			Metadata::<T>::set(asset_id, Some(asset_metadata));
+			Self::ensure_is_owner(asset_id, origin.clone())?;
+
+			Metadata::<T>::try_mutate_exists(asset_id, |maybe_metadata| -> DispatchResult {
+				ensure!(
+					maybe_metadata.as_ref().map_or(true, |metadata| !metadata.is_frozen),
+					Error::<T>::NoPermission
+				);
+
+				// Only reserve or release the difference with the previous deposit.
+				let old_deposit = maybe_metadata.as_ref().map_or(Zero::zero(), |m| m.deposit);
+				let new_deposit = Self::metadata_deposit(name.len() + symbol.len());
+				if new_deposit > old_deposit {
+					T::Currency::reserve(&origin, new_deposit - old_deposit)?;
+				} else {
+					T::Currency::unreserve(&origin, old_deposit - new_deposit);
+				}
+
+				*maybe_metadata = Some(AssetMetadata::new(
+					new_deposit,
+					name.clone(),
+					symbol.clone(),
+					decimals,
+					false,
+				));
+
+				Ok(())
+			})?;
+
 			Self::deposit_event(Event::MetadataSet {
 				asset_id,
 				name,
 				symbol,
+				decimals,
+				is_frozen: false,
 			});
-			// TODO:
-			// - Create a new AssetMetadata instance based on the call arguments.
-			// - Insert this metadata in the Metadata storage, under the asset_id key.
-			// - Deposit a `MetadataSet` event.
 
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::clear_metadata())]
+		pub fn clear_metadata(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_owner(asset_id, origin.clone())?;
+
+			let metadata = Self::metadata(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(!metadata.is_frozen, Error::<T>::NoPermission);
+
+			Metadata::<T>::remove(asset_id);
+			T::Currency::unreserve(&origin, metadata.deposit);
+
+			Self::deposit_event(Event::MetadataCleared { asset_id });
+
+			Ok(())
+		}
+
+		/// Set the metadata of an asset without reserving any deposit. Only callable by root.
+		#[pallet::weight(T::WeightInfo::force_set_metadata(name.len() as u32, symbol.len() as u32))]
+		pub fn force_set_metadata(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			name: BoundedVec<u8, T::MaxLength>,
+			symbol: BoundedVec<u8, T::MaxLength>,
+			decimals: u8,
+			is_frozen: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(Self::asset(asset_id).is_some(), Error::<T>::UnknownAssetId);
+
+			// An existing deposit stays reserved until the metadata is cleared.
+			Metadata::<T>::mutate(asset_id, |maybe_metadata| {
+				let deposit = maybe_metadata.take().map_or(Zero::zero(), |m| m.deposit);
+				*maybe_metadata = Some(AssetMetadata::new(
+					deposit,
+					name.clone(),
+					symbol.clone(),
+					decimals,
+					is_frozen,
+				));
+			});
+
+			Self::deposit_event(Event::MetadataSet {
+				asset_id,
+				name,
+				symbol,
+				decimals,
+				is_frozen,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::mint())]
 		pub fn mint(
 			origin: OriginFor<T>,
 			asset_id: AssetId,
@@ -171,105 +446,371 @@ pub mod pallet {
 		) -> DispatchResult {
 			// TODO:
 			// - Ensure the extrinsic origin is a signed transaction.
-			// - Ensure the caller is the asset owner.
+			// - Ensure the caller is the asset issuer.
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_issuer(asset_id, origin)?;
+
+			Self::do_mint(asset_id, to, amount)?;
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, asset_id: AssetId, amount: u128) -> DispatchResult {
+			// TODO:
+			// - Ensure the extrinsic origin is a signed transaction.
+			let origin = ensure_signed(origin)?;
+
+			Self::do_burn(asset_id, origin, amount)?;
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: u128,
+			to: T::AccountId,
+		) -> DispatchResult {
+			// TODO:
+			// - Ensure the extrinsic origin is a signed transaction.
+			let origin = ensure_signed(origin)?;
+
+			Self::do_transfer(asset_id, origin, to, amount)?;
+
+			Ok(())
+		}
+
+		/// Send `asset_id` to several accounts at once. Either every transfer succeeds, or none
+		/// is applied.
+		#[pallet::weight(T::WeightInfo::transfer_batch(transfers.len() as u32))]
+		#[transactional]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			transfers: BoundedVec<(T::AccountId, u128), T::MaxBatchSize>,
+		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-			Self::ensure_is_owner(asset_id, origin.clone())?;
 
-			let mut minted_amount = 0;
+			let recipients = transfers.len() as u32;
+			let mut total: u128 = 0;
+			for (to, amount) in transfers {
+				total = total.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+				Self::do_transfer(asset_id, origin.clone(), to, amount)?;
+			}
+
+			Self::deposit_event(Event::BatchTransferred {
+				asset_id,
+				from: origin,
+				recipients,
+				total,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			delegate: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			// Approvals would prevent `finish_destroy`.
+			ensure!(
+				details.status == AssetStatus::Live,
+				Error::<T>::AssetNotLive
+			);
+
+			Approvals::<T>::mutate((asset_id, owner.clone(), delegate.clone()), |approved| {
+				*approved = Some(approved.unwrap_or(0).saturating_add(amount));
+			});
+
+			Self::deposit_event(Event::ApprovedTransfer {
+				asset_id,
+				owner,
+				delegate,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			delegate: T::AccountId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(Self::asset(asset_id).is_some(), Error::<T>::UnknownAssetId);
+
+			Approvals::<T>::take((asset_id, owner.clone(), delegate.clone()))
+				.ok_or(Error::<T>::Unapproved)?;
+
+			Self::deposit_event(Event::ApprovalCancelled {
+				asset_id,
+				owner,
+				delegate,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer_approved())]
+		pub fn transfer_approved(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			owner: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			ensure!(Self::asset(asset_id).is_some(), Error::<T>::UnknownAssetId);
+
+			let approved = Self::approvals((asset_id, owner.clone(), delegate.clone()))
+				.ok_or(Error::<T>::Unapproved)?;
+			ensure!(approved >= amount, Error::<T>::Unapproved);
+
+			Self::do_transfer(asset_id, owner.clone(), to, amount)?;
+
+			let remaining = approved - amount;
+			if remaining == 0 {
+				Approvals::<T>::remove((asset_id, owner, delegate));
+			} else {
+				Approvals::<T>::insert((asset_id, owner, delegate), remaining);
+			}
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::freeze_asset())]
+		pub fn freeze_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_freezer(asset_id, origin)?;
 
 			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
 				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				details.is_frozen = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AssetFrozen { asset_id });
+
+			Ok(())
+		}
 
-				let old_supply = details.supply;
-				details.supply = details.supply.saturating_add(amount);
-				minted_amount = details.supply - old_supply;
+		#[pallet::weight(T::WeightInfo::thaw_asset())]
+		pub fn thaw_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_admin(asset_id, origin)?;
 
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				details.is_frozen = false;
 				Ok(())
 			})?;
 
-			Account::<T>::mutate(asset_id, to.clone(), |balance| {
-				*balance += minted_amount;
-			});
+			Self::deposit_event(Event::AssetThawed { asset_id });
 
-			// TODO: Deposit a `Minted` event.
-			if let Some(current_asset) = Asset::<T>::get(asset_id) {
-				Self::deposit_event(Event::Minted {
-					asset_id,
-					owner: to,
-					total_supply: current_asset.supply,
-				});
-				Ok(())
-			} else {
-				Err(DispatchError::from("current asset does not exist"))
-			}
+			Ok(())
 		}
 
-		#[pallet::weight(0)]
-		pub fn burn(origin: OriginFor<T>, asset_id: AssetId, amount: u128) -> DispatchResult {
-			// TODO:
-			// - Ensure the extrinsic origin is a signed transaction.
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		pub fn freeze_account(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			who: T::AccountId,
+		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			Self::ensure_is_freezer(asset_id, origin)?;
+			// Frozen accounts would prevent `finish_destroy`.
+			ensure!(
+				Self::asset(asset_id).map(|details| details.status) == Some(AssetStatus::Live),
+				Error::<T>::AssetNotLive
+			);
+
+			FrozenAccount::<T>::insert(asset_id, who.clone(), true);
+
+			Self::deposit_event(Event::AccountFrozen { asset_id, who });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw_account())]
+		pub fn thaw_account(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_admin(asset_id, origin)?;
+
+			FrozenAccount::<T>::remove(asset_id, who.clone());
+
+			Self::deposit_event(Event::AccountThawed { asset_id, who });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub fn set_team(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			issuer: T::AccountId,
+			admin: T::AccountId,
+			freezer: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_owner(asset_id, origin)?;
 
-			// - Mutate the total supply.
-			let mut old_supply = 0;
-			let burned_amount = cmp::min(amount, Account::<T>::get(asset_id, origin.clone()));
 			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
 				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
-				old_supply = details.supply;
-				details.supply -= burned_amount;
+				details.issuer = issuer.clone();
+				details.admin = admin.clone();
+				details.freezer = freezer.clone();
 				Ok(())
 			})?;
-			// - Mutate the account balance.
-			Account::<T>::mutate(asset_id, origin.clone(), |balance| {
-				*balance = balance.saturating_sub(amount);
-			});
 
-			Self::deposit_event(Event::Burned {
+			Self::deposit_event(Event::TeamChanged {
 				asset_id,
-				owner: origin,
-				total_supply: old_supply - burned_amount,
+				issuer,
+				admin,
+				freezer,
 			});
+
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
-		pub fn transfer(
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
 			origin: OriginFor<T>,
 			asset_id: AssetId,
-			amount: u128,
-			to: T::AccountId,
+			owner: T::AccountId,
 		) -> DispatchResult {
-			// TODO:
-			// - Ensure the extrinsic origin is a signed transaction.
 			let origin = ensure_signed(origin)?;
+			Self::ensure_is_owner(asset_id, origin)?;
 
-			if let Some(_) = Asset::<T>::get(asset_id) {
-				// - Mutate both account balances.
-				let transferred_amount =
-					cmp::min(amount, Account::<T>::get(asset_id, origin.clone()));
-
-				Account::<T>::mutate(asset_id, origin.clone(), |balance| {
-					*balance -= transferred_amount;
-				});
-
-				Account::<T>::mutate(asset_id, to.clone(), |balance| {
-					*balance += transferred_amount;
-				});
-				// - Emit a `Transferred` event.
-				Self::deposit_event(Event::Transferred {
-					asset_id,
-					from: origin,
-					to,
-					amount: transferred_amount,
-				});
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				// The deposits follow the ownership.
+				let metadata_deposit = Self::metadata(asset_id).map_or(Zero::zero(), |m| m.deposit);
+				T::Currency::repatriate_reserved(
+					&details.owner,
+					&owner,
+					details.deposit.saturating_add(metadata_deposit),
+					BalanceStatus::Reserved,
+				)?;
+				details.owner = owner.clone();
 				Ok(())
-			} else {
-				Err(DispatchError::Module(sp_runtime::ModuleError {
-					index: 1,
-					error: [0, 0, 0, 0],
-					message: Some("UnknownAssetId"),
-				}))
-			}
+			})?;
+
+			Self::deposit_event(Event::OwnerChanged { asset_id, owner });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_owner(asset_id, origin)?;
+
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				ensure!(
+					details.status == AssetStatus::Live,
+					Error::<T>::AssetNotLive
+				);
+				details.status = AssetStatus::Destroying;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::DestructionStarted { asset_id });
+
+			Ok(())
+		}
+
+		/// Remove up to `max` accounts, frozen accounts and approvals of an asset being destroyed.
+		#[pallet::weight(T::WeightInfo::destroy_accounts(*max))]
+		pub fn destroy_accounts(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			max: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut accounts_destroyed = 0;
+			let mut accounts_remaining = 0;
+
+			Asset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				ensure!(
+					details.status == AssetStatus::Destroying,
+					Error::<T>::IncorrectStatus
+				);
+
+				for (who, balance) in Account::<T>::drain_prefix(asset_id).take(max as usize) {
+					AccountAssets::<T>::remove(who, asset_id);
+					details.supply = details.supply.saturating_sub(balance);
+					details.accounts = details.accounts.saturating_sub(1);
+					accounts_destroyed += 1;
+				}
+				accounts_remaining = details.accounts;
+
+				Ok(())
+			})?;
+
+			let freezes_destroyed = FrozenAccount::<T>::drain_prefix(asset_id)
+				.take((max - accounts_destroyed) as usize)
+				.count() as u32;
+			let approvals_destroyed = Approvals::<T>::drain_prefix((asset_id,))
+				.take((max - accounts_destroyed - freezes_destroyed) as usize)
+				.count() as u32;
+
+			Self::deposit_event(Event::AccountsDestroyed {
+				asset_id,
+				accounts_destroyed,
+				accounts_remaining,
+				freezes_destroyed,
+				approvals_destroyed,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(
+				details.status == AssetStatus::Destroying,
+				Error::<T>::IncorrectStatus
+			);
+			ensure!(details.accounts == 0, Error::<T>::InUse);
+			ensure!(
+				FrozenAccount::<T>::iter_prefix(asset_id).next().is_none(),
+				Error::<T>::InUse
+			);
+			ensure!(
+				Approvals::<T>::iter_prefix((asset_id,)).next().is_none(),
+				Error::<T>::InUse
+			);
+
+			Asset::<T>::remove(asset_id);
+			let metadata_deposit =
+				Metadata::<T>::take(asset_id).map_or(Zero::zero(), |m| m.deposit);
+			T::Currency::unreserve(
+				&details.owner,
+				details.deposit.saturating_add(metadata_deposit),
+			);
+
+			Self::deposit_event(Event::Destroyed { asset_id });
+
+			Ok(())
 		}
 	}
 }
@@ -284,4 +825,304 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	fn ensure_is_issuer(asset_id: AssetId, account: T::AccountId) -> Result<(), Error<T>> {
+		let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		ensure!(details.issuer == account, Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	fn ensure_is_admin(asset_id: AssetId, account: T::AccountId) -> Result<(), Error<T>> {
+		let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		ensure!(details.admin == account, Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	fn ensure_is_freezer(asset_id: AssetId, account: T::AccountId) -> Result<(), Error<T>> {
+		let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		ensure!(details.freezer == account, Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	fn ensure_not_frozen(asset_id: AssetId, account: T::AccountId) -> Result<(), Error<T>> {
+		let details = Self::asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		ensure!(!details.is_frozen, Error::<T>::Frozen);
+		ensure!(!Self::frozen_account(asset_id, account), Error::<T>::Frozen);
+
+		Ok(())
+	}
+
+	fn do_force_create(
+		asset_id: AssetId,
+		owner: T::AccountId,
+		min_balance: u128,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			!Asset::<T>::contains_key(asset_id),
+			Error::<T>::AlreadyExists
+		);
+		ensure!(min_balance > 0, Error::<T>::MinBalanceZero);
+
+		let details = AssetDetails::new(owner.clone(), Zero::zero(), min_balance);
+		Asset::<T>::insert(asset_id, details);
+
+		// Make sure `create` never picks an ID already taken.
+		if asset_id >= Self::nonce() {
+			Nonce::<T>::set(asset_id.saturating_add(1));
+		}
+
+		Self::deposit_event(Event::<T>::ForceCreated { owner, asset_id });
+
+		Ok(())
+	}
+
+	// Create `amount` new units of `asset_id` in the account of `to`.
+	fn do_mint(asset_id: AssetId, to: T::AccountId, amount: u128) -> Result<(), Error<T>> {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+		let mut total_supply = 0;
+
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> Result<(), Error<T>> {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(
+				details.status == AssetStatus::Live,
+				Error::<T>::AssetNotLive
+			);
+
+			details.supply = details.supply.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+			total_supply = details.supply;
+			Self::increase_balance(details, asset_id, to.clone(), amount)
+		})?;
+
+		Self::deposit_event(Event::Minted {
+			asset_id,
+			owner: to,
+			total_supply,
+		});
+
+		Ok(())
+	}
+
+	// Destroy `amount` units of `asset_id` from the account of `from`.
+	fn do_burn(asset_id: AssetId, from: T::AccountId, amount: u128) -> Result<(), Error<T>> {
+		Self::ensure_not_frozen(asset_id, from.clone())?;
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+		let mut total_supply = 0;
+
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> Result<(), Error<T>> {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(
+				details.status == AssetStatus::Live,
+				Error::<T>::AssetNotLive
+			);
+
+			// Check the new supply before the debit, which is written as soon as it succeeds.
+			let supply =
+				details.supply.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+			Self::decrease_balance(details, asset_id, from.clone(), amount)?;
+			details.supply = supply;
+			total_supply = supply;
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::Burned {
+			asset_id,
+			owner: from,
+			total_supply,
+		});
+
+		Ok(())
+	}
+
+	// Move exactly `amount` of `asset_id` from `from` to `to`.
+	fn do_transfer(
+		asset_id: AssetId,
+		from: T::AccountId,
+		to: T::AccountId,
+		amount: u128,
+	) -> Result<(), Error<T>> {
+		Self::ensure_not_frozen(asset_id, from.clone())?;
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> Result<(), Error<T>> {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(
+				details.status == AssetStatus::Live,
+				Error::<T>::AssetNotLive
+			);
+			// Check the credit of `to` before debiting `from`, which is written as soon as it
+			// succeeds, so that a failed credit cannot lose `amount`.
+			let credited = Self::account(asset_id, to.clone())
+				.checked_add(amount)
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(credited >= details.min_balance, Error::<T>::BalanceLow);
+
+			Self::decrease_balance(details, asset_id, from.clone(), amount)?;
+			Self::increase_balance(details, asset_id, to.clone(), amount)
+		})?;
+
+		Self::deposit_event(Event::Transferred {
+			asset_id,
+			from,
+			to,
+			amount,
+		});
+
+		Ok(())
+	}
+
+	// Withdraw a transaction fee of `amount` from `who`. The fee alone never reaps the account,
+	// but the dispatched call still can before the refund.
+	fn withdraw_fee(asset_id: AssetId, who: T::AccountId, amount: u128) -> Result<(), Error<T>> {
+		Self::ensure_not_frozen(asset_id, who.clone())?;
+
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> Result<(), Error<T>> {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(
+				details.status == AssetStatus::Live,
+				Error::<T>::AssetNotLive
+			);
+			ensure!(
+				Self::account(asset_id, who.clone()).saturating_sub(amount) >= details.min_balance,
+				Error::<T>::BalanceLow
+			);
+
+			Self::decrease_balance(details, asset_id, who, amount)?;
+			details.supply = details.supply.saturating_sub(amount);
+
+			Ok(())
+		})
+	}
+
+	// Give back the unused part of a transaction fee withdrawn with `withdraw_fee`, returning the
+	// amount refunded. The refund is left out of the supply when it cannot be credited, e.g. when
+	// the call reaped the account and the refund is below `min_balance`, as the call has already
+	// been executed.
+	fn refund_fee(asset_id: AssetId, who: T::AccountId, amount: u128) -> u128 {
+		Asset::<T>::try_mutate(asset_id, |maybe_details| -> Result<(), Error<T>> {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			let supply = details.supply.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+			Self::increase_balance(details, asset_id, who, amount)?;
+			details.supply = supply;
+			Ok(())
+		})
+		.map_or(0, |()| amount)
+	}
+
+	// Credit `amount` to `who`, opening its account if it did not hold any of the asset yet.
+	fn increase_balance(
+		details: &mut AssetDetails<T::AccountId, BalanceOf<T>>,
+		asset_id: AssetId,
+		who: T::AccountId,
+		amount: u128,
+	) -> Result<(), Error<T>> {
+		Account::<T>::try_mutate_exists(
+			asset_id,
+			who.clone(),
+			|maybe_balance| -> Result<(), Error<T>> {
+				let balance =
+					maybe_balance.unwrap_or(0).checked_add(amount).ok_or(Error::<T>::Overflow)?;
+				ensure!(balance >= details.min_balance, Error::<T>::BalanceLow);
+
+				if maybe_balance.is_none() {
+					details.accounts = details.accounts.saturating_add(1);
+					AccountAssets::<T>::insert(&who, asset_id, ());
+				}
+				*maybe_balance = Some(balance);
+
+				Ok(())
+			},
+		)
+	}
+
+	// Debit `amount` from `who`, reaping its account once the balance reaches zero.
+	fn decrease_balance(
+		details: &mut AssetDetails<T::AccountId, BalanceOf<T>>,
+		asset_id: AssetId,
+		who: T::AccountId,
+		amount: u128,
+	) -> Result<(), Error<T>> {
+		Account::<T>::try_mutate_exists(
+			asset_id,
+			who.clone(),
+			|maybe_balance| -> Result<(), Error<T>> {
+				let balance = maybe_balance
+					.unwrap_or(0)
+					.checked_sub(amount)
+					.ok_or(Error::<T>::InsufficientBalance)?;
+
+				if balance == 0 {
+					details.accounts = details.accounts.saturating_sub(1);
+					AccountAssets::<T>::remove(&who, asset_id);
+					*maybe_balance = None;
+				} else {
+					ensure!(balance >= details.min_balance, Error::<T>::BalanceLow);
+					*maybe_balance = Some(balance);
+				}
+
+				Ok(())
+			},
+		)
+	}
+
+	/// Check that the supply of every asset is the sum of its balances, and that every balance
+	/// belongs to an existing asset.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (asset_id, details) in Asset::<T>::iter() {
+			let balances = Account::<T>::iter_prefix_values(asset_id)
+				.fold(0u128, |total, balance| total.saturating_add(balance));
+			ensure!(
+				details.supply == balances,
+				"pallet_assets: supply differs from the sum of balances"
+			);
+		}
+
+		for (asset_id, _, _) in Account::<T>::iter() {
+			ensure!(
+				Asset::<T>::contains_key(asset_id),
+				"pallet_assets: balance of an unknown asset"
+			);
+		}
+
+		Ok(())
+	}
+
+	/// The balance of `who` for `asset_id`.
+	pub fn balance_of(asset_id: AssetId, who: T::AccountId) -> u128 {
+		Self::account(asset_id, who)
+	}
+
+	/// The assets `who` holds some balance of.
+	pub fn assets_of(who: T::AccountId) -> Vec<AssetId> {
+		AccountAssets::<T>::iter_key_prefix(who).collect()
+	}
+
+	/// The total supply of `asset_id`, zero if it does not exist.
+	pub fn total_supply(asset_id: AssetId) -> u128 {
+		Self::asset(asset_id).map_or(0, |details| details.supply)
+	}
+
+	/// The name, symbol and decimals of `asset_id`, if its metadata is set.
+	pub fn metadata_of(asset_id: AssetId) -> Option<(Vec<u8>, Vec<u8>, u8)> {
+		Self::metadata(asset_id).map(|metadata| {
+			(
+				metadata.name.into_inner(),
+				metadata.symbol.into_inner(),
+				metadata.decimals,
+			)
+		})
+	}
+
+	// The deposit required to store a metadata whose name and symbol total `bytes` bytes.
+	fn metadata_deposit(bytes: usize) -> BalanceOf<T> {
+		T::MetadataDepositPerByte::get()
+			.saturating_mul((bytes as u32).into())
+			.saturating_add(T::MetadataDepositBase::get())
+	}
 }
@@ -0,0 +1,154 @@
+//! Storage migrations of pallet_assets.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+pub mod v0 {
+	use super::*;
+
+	/// The layout of an asset before v1: no team, deposit, minimum balance nor status.
+	#[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldAssetDetails<AccountId> {
+		pub owner: AccountId,
+		pub supply: u128,
+	}
+
+	/// The layout of asset metadata before v1: no deposit, decimals nor frozen flag.
+	#[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(S))]
+	#[codec(mel_bound())]
+	pub struct OldAssetMetadata<S: Get<u32>> {
+		pub name: BoundedVec<u8, S>,
+		pub symbol: BoundedVec<u8, S>,
+	}
+
+	#[frame_support::storage_alias]
+	pub type Asset<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		AssetId,
+		OldAssetDetails<<T as frame_system::Config>::AccountId>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type Metadata<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		AssetId,
+		OldAssetMetadata<<T as Config>::MaxLength>,
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Translate the v0 `Asset` and `Metadata` entries to the v1 layout, and index the assets held
+	/// by each account in `AccountAssets`.
+	///
+	/// No deposit was reserved in v0, so the migrated assets and metadata hold none. Every account
+	/// already holding some of an asset is kept, so the minimum balance is set to 1. The zero
+	/// balances v0 left behind are removed.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			Asset::<T>::translate::<v0::OldAssetDetails<T::AccountId>, _>(|asset_id, old| {
+				let balances: Vec<_> = Account::<T>::iter_prefix(asset_id).collect();
+				reads += 1 + balances.len() as Weight;
+				writes += 1 + balances.len() as Weight;
+
+				let mut accounts: u32 = 0;
+				for (who, balance) in balances {
+					if balance == 0 {
+						Account::<T>::remove(asset_id, who);
+					} else {
+						AccountAssets::<T>::insert(who, asset_id, ());
+						accounts += 1;
+					}
+				}
+
+				let mut details = AssetDetails::new(old.owner, Zero::zero(), 1);
+				details.supply = old.supply;
+				details.accounts = accounts;
+				Some(details)
+			});
+
+			Metadata::<T>::translate::<v0::OldAssetMetadata<T::MaxLength>, _>(|_, old| {
+				reads += 1;
+				writes += 1;
+				Some(AssetMetadata::new(
+					Zero::zero(),
+					old.name,
+					old.symbol,
+					0,
+					false,
+				))
+			});
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"pallet_assets: expected storage version 0"
+			);
+
+			let mut assets: u32 = 0;
+			for (asset_id, details) in v0::Asset::<T>::iter() {
+				let total = Account::<T>::iter_prefix_values(asset_id)
+					.fold(0u128, |total, balance| total.saturating_add(balance));
+				ensure!(
+					details.supply == total,
+					"pallet_assets: supply differs from the sum of balances"
+				);
+				assets += 1;
+			}
+
+			Ok(assets.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let expected_assets: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "pallet_assets: invalid pre_upgrade state")?;
+
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"pallet_assets: expected storage version 1"
+			);
+
+			Pallet::<T>::do_try_state()?;
+
+			let mut assets: u32 = 0;
+			for (asset_id, details) in Asset::<T>::iter() {
+				let accounts = Account::<T>::iter_prefix(asset_id).count() as u32;
+				ensure!(
+					details.accounts == accounts,
+					"pallet_assets: accounts differs from the number of holders"
+				);
+				assets += 1;
+			}
+			ensure!(
+				assets == expected_assets,
+				"pallet_assets: some assets were lost during the migration"
+			);
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,222 @@
+//! A `SignedExtension` paying transaction fees in an asset of this pallet, for accounts holding no
+//! native currency.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_runtime::{
+		traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+		transaction_validity::{
+			InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+		},
+		FixedPointOperand,
+	},
+	weights::{DispatchInfo, PostDispatchInfo},
+};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use scale_info::TypeInfo;
+use sp_std::fmt;
+
+type OnChargeTransactionOf<T> = <T as pallet_transaction_payment::Config>::OnChargeTransaction;
+type FeeBalanceOf<T> = <OnChargeTransactionOf<T> as OnChargeTransaction<T>>::Balance;
+type LiquidityInfoOf<T> = <OnChargeTransactionOf<T> as OnChargeTransaction<T>>::LiquidityInfo;
+
+/// Handles the transaction fees paid in an asset with `ChargeAssetTxPayment`.
+pub trait HandleAssetFee {
+	/// `amount` of `asset_id` has been paid as a fee. It has already been withdrawn from the payer
+	/// and removed from the supply, so that it is burned unless this credits it to some account.
+	/// The fee cannot be rejected anymore: failures must be handled here.
+	fn handle(asset_id: AssetId, amount: u128);
+}
+
+/// Burn the fees.
+impl HandleAssetFee for () {
+	fn handle(_asset_id: AssetId, _amount: u128) {}
+}
+
+/// What has been withdrawn from the signer before dispatching the call.
+pub enum InitialPayment<T: pallet_transaction_payment::Config> {
+	/// The fee was zero.
+	Nothing,
+	/// The fee was paid in the native currency.
+	Native(LiquidityInfoOf<T>),
+	/// The fee was paid with `amount` of `asset_id`.
+	Asset { asset_id: AssetId, amount: u128 },
+}
+
+/// Charge the transaction fee in `asset_id` when it is set, and in the native currency otherwise.
+///
+/// The fee is converted into an amount of the asset with `Config::FeeConversion` and withdrawn
+/// from `Account` before dispatch. The part of it matching the unused weight is refunded after
+/// dispatch, and what is left is given to `Config::OnAssetFee`. This includes a refund that could
+/// not be credited back, e.g. because the call reaped the account.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeAssetTxPayment<T: pallet_transaction_payment::Config> {
+	#[codec(compact)]
+	tip: FeeBalanceOf<T>,
+	asset_id: Option<AssetId>,
+}
+
+impl<T: pallet_transaction_payment::Config> ChargeAssetTxPayment<T> {
+	/// Pay the fees and `tip` in `asset_id`, or in the native currency if it is `None`.
+	pub fn from(tip: FeeBalanceOf<T>, asset_id: Option<AssetId>) -> Self {
+		Self { tip, asset_id }
+	}
+}
+
+impl<T: Config + pallet_transaction_payment::Config> ChargeAssetTxPayment<T>
+where
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand + Into<BalanceOf<T>>,
+{
+	// Withdraw the fee of the transaction from `who` in `asset_id`. Returns the fee in the native
+	// currency, used to compute the priority of the transaction.
+	fn withdraw_asset_fee(
+		&self,
+		who: &T::AccountId,
+		asset_id: AssetId,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> Result<(FeeBalanceOf<T>, InitialPayment<T>), TransactionValidityError> {
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		if fee.is_zero() {
+			return Ok((fee, InitialPayment::Nothing));
+		}
+
+		let amount = T::FeeConversion::to_asset_balance(fee.into(), asset_id)
+			.map_err(|_| InvalidTransaction::Payment)?;
+		Pallet::<T>::withdraw_fee(asset_id, who.clone(), amount)
+			.map_err(|_| InvalidTransaction::Payment)?;
+
+		Ok((fee, InitialPayment::Asset { asset_id, amount }))
+	}
+}
+
+impl<T: pallet_transaction_payment::Config> fmt::Debug for ChargeAssetTxPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"ChargeAssetTxPayment<{:?}, {:?}>",
+			self.tip, self.asset_id
+		)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + pallet_transaction_payment::Config> SignedExtension for ChargeAssetTxPayment<T>
+where
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand + Into<BalanceOf<T>>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = T::Call;
+	type Pre = (FeeBalanceOf<T>, T::AccountId, InitialPayment<T>);
+
+	const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let asset_id = match self.asset_id {
+			Some(asset_id) => asset_id,
+			None => {
+				return ChargeTransactionPayment::<T>::from(self.tip).validate(who, call, info, len)
+			},
+		};
+
+		let (fee, _) = self.withdraw_asset_fee(who, asset_id, info, len)?;
+		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+
+		Ok(ValidTransaction {
+			priority,
+			..Default::default()
+		})
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let asset_id = match self.asset_id {
+			Some(asset_id) => asset_id,
+			None => {
+				let (tip, who, liquidity_info) = ChargeTransactionPayment::<T>::from(self.tip)
+					.pre_dispatch(who, call, info, len)?;
+				return Ok((tip, who, InitialPayment::Native(liquidity_info)));
+			},
+		};
+
+		let (_, initial_payment) = self.withdraw_asset_fee(who, asset_id, info, len)?;
+
+		Ok((self.tip, who.clone(), initial_payment))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (tip, who, initial_payment) = match pre {
+			Some(pre) => pre,
+			None => return Ok(()),
+		};
+
+		match initial_payment {
+			InitialPayment::Nothing => Ok(()),
+			InitialPayment::Native(liquidity_info) => ChargeTransactionPayment::<T>::post_dispatch(
+				Some((tip, who, liquidity_info)),
+				info,
+				post_info,
+				len,
+				result,
+			),
+			InitialPayment::Asset { asset_id, amount } => {
+				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+					len as u32, info, post_info, tip,
+				);
+				let actual_amount = T::FeeConversion::to_asset_balance(actual_fee.into(), asset_id)
+					.map_err(|_| InvalidTransaction::Payment)?
+					.min(amount);
+
+				let refund = amount - actual_amount;
+				let refunded = if refund > 0 {
+					Pallet::<T>::refund_fee(asset_id, who.clone(), refund)
+				} else {
+					0
+				};
+
+				let actual_fee = amount - refunded;
+				T::OnAssetFee::handle(asset_id, actual_fee);
+
+				Pallet::<T>::deposit_event(Event::AssetTxFeePaid {
+					who,
+					asset_id,
+					actual_fee,
+				});
+
+				Ok(())
+			},
+		}
+	}
+}
@@ -0,0 +1,27 @@
+//! Runtime API to query the assets of this pallet without reading raw storage keys.
+//!
+//! A runtime implements it by forwarding each call to the matching `Pallet` function, e.g.
+//! `AssetsApi::balance_of` to `Assets::balance_of`.
+
+use crate::types::AssetId;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait AssetsApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// The balance of `who` for `asset_id`.
+		fn balance_of(asset_id: AssetId, who: AccountId) -> u128;
+
+		/// The assets `who` holds some balance of.
+		fn assets_of(who: AccountId) -> Vec<AssetId>;
+
+		/// The total supply of `asset_id`, zero if it does not exist.
+		fn total_supply(asset_id: AssetId) -> u128;
+
+		/// The name, symbol and decimals of `asset_id`, if its metadata is set.
+		fn metadata(asset_id: AssetId) -> Option<(Vec<u8>, Vec<u8>, u8)>;
+	}
+}
@@ -13,9 +13,9 @@ mod create {
 
 	#[test]
 	fn ok() {
-		new_test_ext().execute_with(|| {
+		build_and_execute(|| {
 			// The execution went through without error.
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			// The nonce was increased.
 			assert!(Assets::nonce() == 1);
@@ -23,12 +23,26 @@ mod create {
 			// The asset id is 0;
 			let details = Assets::asset(0).unwrap();
 
-			// The sender is the owner of the asset.
+			// The sender is the owner of the asset, and holds every role.
 			assert!(details.owner == ALICE);
+			assert!(details.issuer == ALICE);
+			assert!(details.admin == ALICE);
+			assert!(details.freezer == ALICE);
 
 			// The supply is still 0.
 			assert!(details.supply == 0);
 
+			// The asset is not frozen.
+			assert!(!details.is_frozen);
+
+			// The minimum balance is set, and nobody holds the asset yet.
+			assert!(details.min_balance == 1);
+			assert!(details.accounts == 0);
+
+			// The deposit has been reserved from the owner.
+			assert!(details.deposit == ASSET_DEPOSIT);
+			assert_eq!(Balances::reserved_balance(ALICE), ASSET_DEPOSIT);
+
 			// The event was deposited
 			assert_eq!(
 				last_event(),
@@ -42,8 +56,90 @@ mod create {
 
 	#[test]
 	fn must_be_signed() {
-		new_test_ext().execute_with(|| {
-			assert_noop!(Assets::create(Origin::none()), BadOrigin);
+		build_and_execute(|| {
+			assert_noop!(Assets::create(Origin::none(), 1), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_have_min_balance() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::create(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::MinBalanceZero
+			);
+		})
+	}
+
+	#[test]
+	fn must_afford_deposit() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::create(Origin::signed(DAVE), 1),
+				pallet_balances::Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+}
+
+mod force_create {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::force_create(Origin::root(), 5, DAVE, 10));
+
+			let details = Assets::asset(5).unwrap();
+			assert_eq!(details.owner, DAVE);
+			assert_eq!(details.min_balance, 10);
+			// No deposit is taken.
+			assert_eq!(details.deposit, 0);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::ForceCreated {
+					owner: DAVE,
+					asset_id: 5
+				})
+			);
+
+			// The next created asset does not collide with the forced one.
+			assert_eq!(Assets::nonce(), 6);
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_eq!(Assets::asset(6).unwrap().owner, ALICE);
+		})
+	}
+
+	#[test]
+	fn must_be_root() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::force_create(Origin::signed(ALICE), 0, ALICE, 1),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_exist() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::force_create(Origin::root(), 0, BOB, 1),
+				Error::<TestRuntime>::AlreadyExists
+			);
+		})
+	}
+
+	#[test]
+	fn must_have_min_balance() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::force_create(Origin::root(), 0, BOB, 0),
+				Error::<TestRuntime>::MinBalanceZero
+			);
 		})
 	}
 }
@@ -54,8 +150,8 @@ mod set_metadata {
 
 	#[test]
 	fn ok() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let name: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TestAsset".as_bytes().to_vec()).unwrap();
@@ -66,7 +162,8 @@ mod set_metadata {
 				Origin::signed(ALICE),
 				0,
 				name.clone(),
-				symbol.clone()
+				symbol.clone(),
+				18
 			));
 
 			let metadata = Assets::metadata(0).unwrap();
@@ -74,6 +171,13 @@ mod set_metadata {
 			// Metadata has been set
 			assert_eq!(metadata.name, name);
 			assert_eq!(metadata.symbol, symbol);
+			assert_eq!(metadata.decimals, 18);
+			assert!(!metadata.is_frozen);
+
+			// The deposit has been reserved from the owner.
+			let deposit = METADATA_DEPOSIT_BASE + METADATA_DEPOSIT_PER_BYTE * 13;
+			assert_eq!(metadata.deposit, deposit);
+			assert_eq!(Balances::reserved_balance(ALICE), ASSET_DEPOSIT + deposit);
 
 			// The event was deposited
 			assert_eq!(
@@ -81,22 +185,101 @@ mod set_metadata {
 				Event::Assets(crate::Event::MetadataSet {
 					asset_id: 0,
 					name,
-					symbol
+					symbol,
+					decimals: 18,
+					is_frozen: false
 				})
 			)
 		})
 	}
 
+	#[test]
+	fn update_adjusts_deposit() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18
+			));
+			assert_eq!(
+				Balances::reserved_balance(ALICE),
+				ASSET_DEPOSIT + METADATA_DEPOSIT_BASE + 13
+			);
+
+			// A shorter metadata releases part of the deposit.
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"Test".as_bytes().to_vec().try_into().unwrap(),
+				"T".as_bytes().to_vec().try_into().unwrap(),
+				6
+			));
+			assert_eq!(
+				Balances::reserved_balance(ALICE),
+				ASSET_DEPOSIT + METADATA_DEPOSIT_BASE + 5
+			);
+			assert_eq!(Assets::metadata(0).unwrap().decimals, 6);
+		})
+	}
+
+	#[test]
+	fn must_afford_deposit() {
+		build_and_execute(|| {
+			assert_ok!(Assets::force_create(Origin::root(), 0, DAVE, 1));
+
+			assert_noop!(
+				Assets::set_metadata(
+					Origin::signed(DAVE),
+					0,
+					"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+					"TASS".as_bytes().to_vec().try_into().unwrap(),
+					18
+				),
+				pallet_balances::Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_frozen() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::force_set_metadata(
+				Origin::root(),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18,
+				true
+			));
+
+			assert_noop!(
+				Assets::set_metadata(
+					Origin::signed(ALICE),
+					0,
+					"Other".as_bytes().to_vec().try_into().unwrap(),
+					"OTH".as_bytes().to_vec().try_into().unwrap(),
+					18
+				),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+
 	#[test]
 	fn must_be_signed() {
-		new_test_ext().execute_with(|| {
+		build_and_execute(|| {
 			let name: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TestAsset".as_bytes().to_vec()).unwrap();
 			let symbol: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TASS".as_bytes().to_vec()).unwrap();
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 			assert_noop!(
-				Assets::set_metadata(Origin::none(), 0, name, symbol),
+				Assets::set_metadata(Origin::none(), 0, name, symbol, 18),
 				BadOrigin
 			);
 		})
@@ -104,13 +287,13 @@ mod set_metadata {
 
 	#[test]
 	fn must_exist() {
-		new_test_ext().execute_with(|| {
+		build_and_execute(|| {
 			let name: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TestAsset".as_bytes().to_vec()).unwrap();
 			let symbol: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TASS".as_bytes().to_vec()).unwrap();
 			assert_noop!(
-				Assets::set_metadata(Origin::signed(ALICE), 0, name, symbol,),
+				Assets::set_metadata(Origin::signed(ALICE), 0, name, symbol, 18),
 				Error::<TestRuntime>::UnknownAssetId
 			);
 		})
@@ -118,28 +301,230 @@ mod set_metadata {
 
 	#[test]
 	fn must_be_owner() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let name: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TestAsset".as_bytes().to_vec()).unwrap();
 			let symbol: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				BoundedVec::try_from("TASS".as_bytes().to_vec()).unwrap();
 			assert_noop!(
-				Assets::set_metadata(Origin::signed(BOB), 0, name, symbol,),
+				Assets::set_metadata(Origin::signed(BOB), 0, name, symbol, 18),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+}
+
+mod clear_metadata {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18
+			));
+
+			assert_ok!(Assets::clear_metadata(Origin::signed(ALICE), 0));
+
+			// Metadata has been removed and the deposit released.
+			assert!(Assets::metadata(0).is_none());
+			assert_eq!(Balances::reserved_balance(ALICE), ASSET_DEPOSIT);
+			assert_eq!(
+				Balances::free_balance(ALICE),
+				INITIAL_BALANCE - ASSET_DEPOSIT
+			);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::MetadataCleared { asset_id: 0 })
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(Assets::clear_metadata(Origin::none(), 0), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::clear_metadata(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+
+			// The metadata itself must exist too.
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::clear_metadata(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_owner() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18
+			));
+			assert_noop!(
+				Assets::clear_metadata(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_frozen() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::force_set_metadata(
+				Origin::root(),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18,
+				true
+			));
+			assert_noop!(
+				Assets::clear_metadata(Origin::signed(ALICE), 0),
 				Error::<TestRuntime>::NoPermission
 			);
 		})
 	}
 }
 
+mod force_set_metadata {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			let name: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
+				BoundedVec::try_from("TestAsset".as_bytes().to_vec()).unwrap();
+			let symbol: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
+				BoundedVec::try_from("TASS".as_bytes().to_vec()).unwrap();
+
+			assert_ok!(Assets::force_set_metadata(
+				Origin::root(),
+				0,
+				name.clone(),
+				symbol.clone(),
+				12,
+				true
+			));
+
+			let metadata = Assets::metadata(0).unwrap();
+			assert_eq!(metadata.name, name);
+			assert_eq!(metadata.decimals, 12);
+			assert!(metadata.is_frozen);
+			// No deposit is taken.
+			assert_eq!(metadata.deposit, 0);
+			assert_eq!(Balances::reserved_balance(ALICE), ASSET_DEPOSIT);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::MetadataSet {
+					asset_id: 0,
+					name,
+					symbol,
+					decimals: 12,
+					is_frozen: true
+				})
+			);
+		})
+	}
+
+	#[test]
+	fn keeps_existing_deposit() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18
+			));
+			let deposit = Assets::metadata(0).unwrap().deposit;
+
+			assert_ok!(Assets::force_set_metadata(
+				Origin::root(),
+				0,
+				"Other".as_bytes().to_vec().try_into().unwrap(),
+				"OTH".as_bytes().to_vec().try_into().unwrap(),
+				18,
+				false
+			));
+			assert_eq!(Assets::metadata(0).unwrap().deposit, deposit);
+			assert_eq!(Balances::reserved_balance(ALICE), ASSET_DEPOSIT + deposit);
+		})
+	}
+
+	#[test]
+	fn must_be_root() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::force_set_metadata(
+					Origin::signed(ALICE),
+					0,
+					"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+					"TASS".as_bytes().to_vec().try_into().unwrap(),
+					18,
+					false
+				),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::force_set_metadata(
+					Origin::root(),
+					0,
+					"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+					"TASS".as_bytes().to_vec().try_into().unwrap(),
+					18,
+					false
+				),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+}
+
 mod mint {
 	use super::*;
 
 	#[test]
 	fn ok() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let mut total_supply = Assets::asset(0).unwrap().supply;
 			assert_eq!(total_supply, 0);
@@ -185,9 +570,9 @@ mod mint {
 	}
 
 	#[test]
-	fn ok_saturating() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+	fn must_not_overflow() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let first_mint_amount = std::u128::MAX - 50;
 			let second_mint_amount = 100;
@@ -197,29 +582,38 @@ mod mint {
 				first_mint_amount,
 				ALICE
 			));
-			assert_ok!(Assets::mint(
-				Origin::signed(ALICE),
-				0,
-				second_mint_amount,
-				BOB
-			));
+			assert_noop!(
+				Assets::mint(Origin::signed(ALICE), 0, second_mint_amount, BOB),
+				Error::<TestRuntime>::Overflow
+			);
+
+			assert_eq!(Assets::asset(0).unwrap().supply, first_mint_amount);
+			assert_eq!(Assets::account(0, BOB), 0);
+		})
+	}
 
-			assert_eq!(Assets::asset(0).unwrap().supply, std::u128::MAX);
-			assert_eq!(Assets::account(0, BOB), 50);
+	#[test]
+	fn must_not_be_zero() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::mint(Origin::signed(ALICE), 0, 0, BOB),
+				Error::<TestRuntime>::ZeroAmount
+			);
 		})
 	}
 
 	#[test]
 	fn must_be_signed() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 			assert_noop!(Assets::mint(Origin::none(), 0, 100, BOB), BadOrigin);
 		})
 	}
 
 	#[test]
 	fn must_exist() {
-		new_test_ext().execute_with(|| {
+		build_and_execute(|| {
 			assert_noop!(
 				Assets::mint(Origin::signed(ALICE), 0, 100, BOB),
 				Error::<TestRuntime>::UnknownAssetId
@@ -229,8 +623,8 @@ mod mint {
 
 	#[test]
 	fn must_be_owner() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			assert_noop!(
 				Assets::mint(Origin::signed(BOB), 0, 100, BOB),
@@ -245,8 +639,8 @@ mod burn {
 
 	#[test]
 	fn ok() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let mint_amount = 100;
 			let burn_amount = 50;
@@ -273,45 +667,45 @@ mod burn {
 	}
 
 	#[test]
-	fn ok_saturating() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+	fn must_have_enough_balance() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let mint_amount = 100;
 			let burn_amount = mint_amount + 1;
 			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, mint_amount, ALICE));
 			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, mint_amount, BOB));
 
-			let total_supply = Assets::asset(0).unwrap().supply;
-
-			assert_ok!(Assets::burn(Origin::signed(BOB), 0, burn_amount));
-			// Total supply and account have been reduced by mint_amount.
-			assert_eq!(Assets::asset(0).unwrap().supply, total_supply - mint_amount);
-			assert_eq!(Assets::account(0, BOB), 0);
+			assert_noop!(
+				Assets::burn(Origin::signed(BOB), 0, burn_amount),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
 
-			// The event was deposited
-			assert_eq!(
-				last_event(),
-				Event::Assets(crate::Event::Burned {
-					asset_id: 0,
-					owner: BOB,
-					total_supply: total_supply - mint_amount,
-				})
+	#[test]
+	fn must_not_be_zero() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_noop!(
+				Assets::burn(Origin::signed(BOB), 0, 0),
+				Error::<TestRuntime>::ZeroAmount
 			);
 		})
 	}
 
 	#[test]
 	fn must_be_signed() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 			assert_noop!(Assets::burn(Origin::none(), 0, 100), BadOrigin);
 		})
 	}
 
 	#[test]
 	fn must_exist() {
-		new_test_ext().execute_with(|| {
+		build_and_execute(|| {
 			assert_noop!(
 				Assets::burn(Origin::signed(ALICE), 0, 100),
 				Error::<TestRuntime>::UnknownAssetId
@@ -325,8 +719,8 @@ mod transfer {
 
 	#[test]
 	fn ok() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let mint_amount = 100;
 			let transfer_amount = 50;
@@ -361,37 +755,45 @@ mod transfer {
 	}
 
 	#[test]
-	fn ok_saturating() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+	fn must_have_enough_balance() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 
 			let mint_amount = 100;
 			let transfer_amount = mint_amount + 1;
 			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, mint_amount, BOB));
 
-			assert_ok!(Assets::transfer(
-				Origin::signed(BOB),
-				0,
-				transfer_amount,
-				ALICE
-			));
-			// Account has only been modified by mint_amount.
-			assert_eq!(Assets::account(0, BOB), 0);
-			assert_eq!(Assets::account(0, ALICE), mint_amount);
-		})
+			// Nothing is moved when the balance is too low.
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, transfer_amount, ALICE),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_zero() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, 0, ALICE),
+				Error::<TestRuntime>::ZeroAmount
+			);
+		})
 	}
 
 	#[test]
 	fn must_be_signed() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::create(Origin::signed(ALICE)));
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
 			assert_noop!(Assets::transfer(Origin::none(), 0, 100, BOB), BadOrigin);
 		})
 	}
 
 	#[test]
 	fn must_exist() {
-		new_test_ext().execute_with(|| {
+		build_and_execute(|| {
 			assert_noop!(
 				Assets::transfer(Origin::signed(ALICE), 0, 100, BOB),
 				Error::<TestRuntime>::UnknownAssetId
@@ -399,3 +801,952 @@ mod transfer {
 		})
 	}
 }
+
+mod transfer_batch {
+	use super::*;
+
+	fn batch(transfers: Vec<(u64, u128)>) -> BoundedVec<(u64, u128), MaxBatchSize> {
+		transfers.try_into().unwrap()
+	}
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, ALICE));
+
+			assert_ok!(Assets::transfer_batch(
+				Origin::signed(ALICE),
+				0,
+				batch(vec![(BOB, 30), (CHARLIE, 20)])
+			));
+
+			assert_eq!(Assets::account(0, ALICE), 50);
+			assert_eq!(Assets::account(0, BOB), 30);
+			assert_eq!(Assets::account(0, CHARLIE), 20);
+
+			// One event per transfer, then a summary.
+			let events: Vec<Event> = frame_system::Pallet::<TestRuntime>::events()
+				.into_iter()
+				.map(|record| record.event)
+				.collect();
+			assert_eq!(
+				events[events.len() - 3..],
+				[
+					Event::Assets(crate::Event::Transferred {
+						asset_id: 0,
+						from: ALICE,
+						to: BOB,
+						amount: 30
+					}),
+					Event::Assets(crate::Event::Transferred {
+						asset_id: 0,
+						from: ALICE,
+						to: CHARLIE,
+						amount: 20
+					}),
+					Event::Assets(crate::Event::BatchTransferred {
+						asset_id: 0,
+						from: ALICE,
+						recipients: 2,
+						total: 50
+					}),
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn is_atomic() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, ALICE));
+
+			// The first transfer is reverted along with the failing one.
+			assert_noop!(
+				Assets::transfer_batch(
+					Origin::signed(ALICE),
+					0,
+					batch(vec![(BOB, 60), (CHARLIE, 60)])
+				),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::transfer_batch(Origin::none(), 0, batch(vec![(BOB, 10)])),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::transfer_batch(Origin::signed(ALICE), 0, batch(vec![(BOB, 10)])),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+}
+
+mod approve_transfer {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+			assert_eq!(Assets::approvals((0, ALICE, BOB)), Some(50));
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::ApprovedTransfer {
+					asset_id: 0,
+					owner: ALICE,
+					delegate: BOB,
+					amount: 50
+				})
+			);
+
+			// Approvals are cumulative.
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 25));
+			assert_eq!(Assets::approvals((0, ALICE, BOB)), Some(75));
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::approve_transfer(Origin::none(), 0, BOB, 50),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_live() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::start_destroy(Origin::signed(ALICE), 0));
+
+			// Even an account holding nothing cannot block the destruction.
+			assert_noop!(
+				Assets::approve_transfer(Origin::signed(BOB), 0, CHARLIE, 50),
+				Error::<TestRuntime>::AssetNotLive
+			);
+			assert_ok!(Assets::finish_destroy(Origin::signed(BOB), 0));
+		})
+	}
+}
+
+mod cancel_approval {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+
+			assert_ok!(Assets::cancel_approval(Origin::signed(ALICE), 0, BOB));
+			assert_eq!(Assets::approvals((0, ALICE, BOB)), None);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::ApprovalCancelled {
+					asset_id: 0,
+					owner: ALICE,
+					delegate: BOB
+				})
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(Assets::cancel_approval(Origin::none(), 0, BOB), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::cancel_approval(Origin::signed(ALICE), 0, BOB),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_approved() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+
+			// Only the owner can cancel its own approval.
+			assert_noop!(
+				Assets::cancel_approval(Origin::signed(BOB), 0, ALICE),
+				Error::<TestRuntime>::Unapproved
+			);
+		})
+	}
+}
+
+mod transfer_approved {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, ALICE));
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+
+			assert_ok!(Assets::transfer_approved(
+				Origin::signed(BOB),
+				0,
+				ALICE,
+				CHARLIE,
+				30
+			));
+			// Balances have been updated.
+			assert_eq!(Assets::account(0, ALICE), 70);
+			assert_eq!(Assets::account(0, CHARLIE), 30);
+			// The approval has been consumed.
+			assert_eq!(Assets::approvals((0, ALICE, BOB)), Some(20));
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::Transferred {
+					asset_id: 0,
+					from: ALICE,
+					to: CHARLIE,
+					amount: 30
+				})
+			);
+
+			// Using the whole approval removes it.
+			assert_ok!(Assets::transfer_approved(
+				Origin::signed(BOB),
+				0,
+				ALICE,
+				BOB,
+				20
+			));
+			assert_eq!(Assets::account(0, BOB), 20);
+			assert_eq!(Assets::approvals((0, ALICE, BOB)), None);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::transfer_approved(Origin::none(), 0, ALICE, BOB, 50),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::transfer_approved(Origin::signed(BOB), 0, ALICE, BOB, 50),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_approved() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, ALICE));
+
+			assert_noop!(
+				Assets::transfer_approved(Origin::signed(BOB), 0, ALICE, BOB, 50),
+				Error::<TestRuntime>::Unapproved
+			);
+
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+			assert_noop!(
+				Assets::transfer_approved(Origin::signed(BOB), 0, ALICE, BOB, 51),
+				Error::<TestRuntime>::Unapproved
+			);
+		})
+	}
+
+	#[test]
+	fn must_have_enough_balance() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 10, ALICE));
+			assert_ok!(Assets::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+
+			// The approval is left untouched.
+			assert_noop!(
+				Assets::transfer_approved(Origin::signed(BOB), 0, ALICE, BOB, 20),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+}
+
+mod freeze_asset {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_ok!(Assets::freeze_asset(Origin::signed(ALICE), 0));
+			assert!(Assets::asset(0).unwrap().is_frozen);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AssetFrozen { asset_id: 0 })
+			);
+
+			// Nobody can move or burn a frozen asset.
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, 50, ALICE),
+				Error::<TestRuntime>::Frozen
+			);
+			assert_noop!(
+				Assets::burn(Origin::signed(BOB), 0, 50),
+				Error::<TestRuntime>::Frozen
+			);
+
+			assert_ok!(Assets::thaw_asset(Origin::signed(ALICE), 0));
+			assert!(!Assets::asset(0).unwrap().is_frozen);
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AssetThawed { asset_id: 0 })
+			);
+
+			assert_ok!(Assets::transfer(Origin::signed(BOB), 0, 50, ALICE));
+			assert_ok!(Assets::burn(Origin::signed(BOB), 0, 50));
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(Assets::freeze_asset(Origin::none(), 0), BadOrigin);
+			assert_noop!(Assets::thaw_asset(Origin::none(), 0), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::freeze_asset(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_owner() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::freeze_asset(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_noop!(
+				Assets::thaw_asset(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+}
+
+mod freeze_account {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, CHARLIE));
+
+			assert_ok!(Assets::freeze_account(Origin::signed(ALICE), 0, BOB));
+			assert!(Assets::frozen_account(0, BOB));
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AccountFrozen {
+					asset_id: 0,
+					who: BOB
+				})
+			);
+
+			// A frozen account can neither send nor burn.
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, 50, ALICE),
+				Error::<TestRuntime>::Frozen
+			);
+			assert_noop!(
+				Assets::burn(Origin::signed(BOB), 0, 50),
+				Error::<TestRuntime>::Frozen
+			);
+			// Other accounts are not affected, and can still send to it.
+			assert_ok!(Assets::transfer(Origin::signed(CHARLIE), 0, 50, BOB));
+
+			assert_ok!(Assets::thaw_account(Origin::signed(ALICE), 0, BOB));
+			assert!(!Assets::frozen_account(0, BOB));
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AccountThawed {
+					asset_id: 0,
+					who: BOB
+				})
+			);
+
+			assert_ok!(Assets::transfer(Origin::signed(BOB), 0, 50, ALICE));
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(Assets::freeze_account(Origin::none(), 0, BOB), BadOrigin);
+			assert_noop!(Assets::thaw_account(Origin::none(), 0, BOB), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::freeze_account(Origin::signed(ALICE), 0, BOB),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_owner() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::freeze_account(Origin::signed(BOB), 0, BOB),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_noop!(
+				Assets::thaw_account(Origin::signed(BOB), 0, BOB),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_live() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::start_destroy(Origin::signed(ALICE), 0));
+
+			assert_noop!(
+				Assets::freeze_account(Origin::signed(ALICE), 0, BOB),
+				Error::<TestRuntime>::AssetNotLive
+			);
+			assert_ok!(Assets::finish_destroy(Origin::signed(ALICE), 0));
+		})
+	}
+}
+
+mod set_team {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			assert_ok!(Assets::set_team(
+				Origin::signed(ALICE),
+				0,
+				BOB,
+				CHARLIE,
+				BOB
+			));
+
+			let details = Assets::asset(0).unwrap();
+			assert_eq!(details.owner, ALICE);
+			assert_eq!(details.issuer, BOB);
+			assert_eq!(details.admin, CHARLIE);
+			assert_eq!(details.freezer, BOB);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::TeamChanged {
+					asset_id: 0,
+					issuer: BOB,
+					admin: CHARLIE,
+					freezer: BOB
+				})
+			);
+		})
+	}
+
+	#[test]
+	fn roles_are_enforced() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_team(
+				Origin::signed(ALICE),
+				0,
+				BOB,
+				CHARLIE,
+				BOB
+			));
+
+			// Only the issuer can mint.
+			assert_noop!(
+				Assets::mint(Origin::signed(ALICE), 0, 100, ALICE),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_ok!(Assets::mint(Origin::signed(BOB), 0, 100, ALICE));
+
+			// Only the freezer can freeze.
+			assert_noop!(
+				Assets::freeze_asset(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_noop!(
+				Assets::freeze_account(Origin::signed(CHARLIE), 0, ALICE),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_ok!(Assets::freeze_asset(Origin::signed(BOB), 0));
+			assert_ok!(Assets::freeze_account(Origin::signed(BOB), 0, ALICE));
+
+			// Only the admin can thaw.
+			assert_noop!(
+				Assets::thaw_asset(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_noop!(
+				Assets::thaw_account(Origin::signed(ALICE), 0, ALICE),
+				Error::<TestRuntime>::NoPermission
+			);
+			assert_ok!(Assets::thaw_asset(Origin::signed(CHARLIE), 0));
+			assert_ok!(Assets::thaw_account(Origin::signed(CHARLIE), 0, ALICE));
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::set_team(Origin::none(), 0, BOB, BOB, BOB),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::set_team(Origin::signed(ALICE), 0, BOB, BOB, BOB),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_owner() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_team(Origin::signed(ALICE), 0, BOB, BOB, BOB));
+
+			// Holding every other role is not enough.
+			assert_noop!(
+				Assets::set_team(Origin::signed(BOB), 0, BOB, BOB, BOB),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+}
+
+mod transfer_ownership {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			assert_ok!(Assets::transfer_ownership(Origin::signed(ALICE), 0, BOB));
+
+			let details = Assets::asset(0).unwrap();
+			assert_eq!(details.owner, BOB);
+			// The team is left untouched.
+			assert_eq!(details.issuer, ALICE);
+
+			// The event was deposited
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::OwnerChanged {
+					asset_id: 0,
+					owner: BOB
+				})
+			);
+
+			// The former owner lost its permissions.
+			assert_noop!(
+				Assets::transfer_ownership(Origin::signed(ALICE), 0, ALICE),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+
+	#[test]
+	fn moves_deposits() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18
+			));
+			let deposit = Assets::metadata(0).unwrap().deposit;
+
+			assert_ok!(Assets::transfer_ownership(Origin::signed(ALICE), 0, BOB));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(
+				Balances::free_balance(ALICE),
+				INITIAL_BALANCE - ASSET_DEPOSIT - deposit
+			);
+			assert_eq!(Balances::reserved_balance(BOB), ASSET_DEPOSIT + deposit);
+
+			// The new owner gets the deposit back when clearing.
+			assert_ok!(Assets::clear_metadata(Origin::signed(BOB), 0));
+			assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + deposit);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::transfer_ownership(Origin::none(), 0, BOB),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::transfer_ownership(Origin::signed(ALICE), 0, BOB),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_owner() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::transfer_ownership(Origin::signed(BOB), 0, BOB),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+}
+
+mod min_balance {
+	use super::*;
+	use crate::pallet::Account;
+
+	#[test]
+	fn accounts_are_counted_and_reaped() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+
+			assert_ok!(Assets::transfer(Origin::signed(BOB), 0, 40, CHARLIE));
+			assert_eq!(Assets::asset(0).unwrap().accounts, 2);
+
+			// Sending the whole balance removes the account.
+			assert_ok!(Assets::transfer(Origin::signed(BOB), 0, 60, CHARLIE));
+			assert!(!Account::<TestRuntime>::contains_key(0, BOB));
+			assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+
+			// So does burning it.
+			assert_ok!(Assets::burn(Origin::signed(CHARLIE), 0, 100));
+			assert!(!Account::<TestRuntime>::contains_key(0, CHARLIE));
+			assert_eq!(Assets::asset(0).unwrap().accounts, 0);
+		})
+	}
+
+	#[test]
+	fn cannot_leave_dust() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, 95, CHARLIE),
+				Error::<TestRuntime>::BalanceLow
+			);
+			assert_noop!(
+				Assets::burn(Origin::signed(BOB), 0, 95),
+				Error::<TestRuntime>::BalanceLow
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_create_dust() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+
+			assert_noop!(
+				Assets::mint(Origin::signed(ALICE), 0, 5, BOB),
+				Error::<TestRuntime>::BalanceLow
+			);
+
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, 5, CHARLIE),
+				Error::<TestRuntime>::BalanceLow
+			);
+
+			// Topping up an existing account is fine.
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 10, CHARLIE));
+			assert_ok!(Assets::transfer(Origin::signed(BOB), 0, 5, CHARLIE));
+			assert_eq!(Assets::account(0, CHARLIE), 15);
+		})
+	}
+}
+
+mod destroy {
+	use super::*;
+	use crate::types::AssetStatus;
+
+	#[test]
+	fn ok() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				"TestAsset".as_bytes().to_vec().try_into().unwrap(),
+				"TASS".as_bytes().to_vec().try_into().unwrap(),
+				18
+			));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, ALICE));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::freeze_account(Origin::signed(ALICE), 0, CHARLIE));
+			assert_ok!(Assets::approve_transfer(Origin::signed(BOB), 0, ALICE, 50));
+
+			assert_ok!(Assets::start_destroy(Origin::signed(ALICE), 0));
+			assert_eq!(Assets::asset(0).unwrap().status, AssetStatus::Destroying);
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::DestructionStarted { asset_id: 0 })
+			);
+
+			// Removal is bounded by `max`.
+			assert_ok!(Assets::destroy_accounts(Origin::signed(BOB), 0, 1));
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AccountsDestroyed {
+					asset_id: 0,
+					accounts_destroyed: 1,
+					accounts_remaining: 1,
+					freezes_destroyed: 0,
+					approvals_destroyed: 0
+				})
+			);
+			assert_noop!(
+				Assets::finish_destroy(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::InUse
+			);
+
+			assert_ok!(Assets::destroy_accounts(Origin::signed(BOB), 0, 10));
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AccountsDestroyed {
+					asset_id: 0,
+					accounts_destroyed: 1,
+					accounts_remaining: 0,
+					freezes_destroyed: 1,
+					approvals_destroyed: 1
+				})
+			);
+			let details = Assets::asset(0).unwrap();
+			assert_eq!(details.supply, 0);
+			assert_eq!(details.accounts, 0);
+
+			assert_ok!(Assets::finish_destroy(Origin::signed(BOB), 0));
+			assert!(Assets::asset(0).is_none());
+			assert!(Assets::metadata(0).is_none());
+			// Both the asset and metadata deposits are released.
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+			assert_eq!(Assets::account(0, ALICE), 0);
+			assert_eq!(Assets::approvals((0, BOB, ALICE)), None);
+			assert!(!Assets::frozen_account(0, CHARLIE));
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::Destroyed { asset_id: 0 })
+			);
+		})
+	}
+
+	#[test]
+	fn asset_is_not_live() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::start_destroy(Origin::signed(ALICE), 0));
+
+			assert_noop!(
+				Assets::mint(Origin::signed(ALICE), 0, 100, BOB),
+				Error::<TestRuntime>::AssetNotLive
+			);
+			assert_noop!(
+				Assets::burn(Origin::signed(BOB), 0, 50),
+				Error::<TestRuntime>::AssetNotLive
+			);
+			assert_noop!(
+				Assets::transfer(Origin::signed(BOB), 0, 50, ALICE),
+				Error::<TestRuntime>::AssetNotLive
+			);
+			assert_noop!(
+				Assets::start_destroy(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::AssetNotLive
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_destroying() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			assert_noop!(
+				Assets::destroy_accounts(Origin::signed(ALICE), 0, 10),
+				Error::<TestRuntime>::IncorrectStatus
+			);
+			assert_noop!(
+				Assets::finish_destroy(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::IncorrectStatus
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(Assets::start_destroy(Origin::none(), 0), BadOrigin);
+			assert_noop!(Assets::destroy_accounts(Origin::none(), 0, 10), BadOrigin);
+			assert_noop!(Assets::finish_destroy(Origin::none(), 0), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		build_and_execute(|| {
+			assert_noop!(
+				Assets::start_destroy(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+			assert_noop!(
+				Assets::destroy_accounts(Origin::signed(ALICE), 0, 10),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+			assert_noop!(
+				Assets::finish_destroy(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_owner() {
+		build_and_execute(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_noop!(
+				Assets::start_destroy(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NoPermission
+			);
+		})
+	}
+}
+
+mod do_try_state {
+	use super::*;
+	use crate::pallet::Account;
+
+	#[test]
+	fn detects_supply_mismatch() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::do_try_state());
+
+			Account::<TestRuntime>::insert(0, BOB, 50);
+			assert!(Assets::do_try_state().is_err());
+		})
+	}
+
+	#[test]
+	fn detects_unknown_asset() {
+		new_test_ext().execute_with(|| {
+			Account::<TestRuntime>::insert(0, BOB, 50);
+			assert!(Assets::do_try_state().is_err());
+		})
+	}
+}
@@ -0,0 +1,209 @@
+use crate::{tests::mock::*, Error};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::tokens::{
+		fungibles::{Create, Inspect, Mutate, Transfer},
+		DepositConsequence, WithdrawConsequence,
+	},
+};
+
+mod inspect {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_eq!(<Assets as Inspect<u64>>::total_issuance(0), 100);
+			assert_eq!(<Assets as Inspect<u64>>::minimum_balance(0), 10);
+			assert_eq!(<Assets as Inspect<u64>>::balance(0, &BOB), 100);
+			assert_eq!(
+				<Assets as Inspect<u64>>::reducible_balance(0, &BOB, false),
+				100
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::reducible_balance(0, &BOB, true),
+				90
+			);
+
+			// Unknown assets hold nothing.
+			assert_eq!(<Assets as Inspect<u64>>::total_issuance(1), 0);
+			assert_eq!(<Assets as Inspect<u64>>::balance(1, &BOB), 0);
+		})
+	}
+
+	#[test]
+	fn frozen_balance_is_not_reducible() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::freeze_account(Origin::signed(ALICE), 0, BOB));
+
+			assert_eq!(
+				<Assets as Inspect<u64>>::reducible_balance(0, &BOB, false),
+				0
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_withdraw(0, &BOB, 10),
+				WithdrawConsequence::Frozen
+			);
+		})
+	}
+
+	#[test]
+	fn can_deposit() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_deposit(0, &BOB, 100, true),
+				DepositConsequence::UnknownAsset
+			);
+
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_deposit(0, &BOB, 5, true),
+				DepositConsequence::BelowMinimum
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_deposit(0, &BOB, 100, true),
+				DepositConsequence::Success
+			);
+
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, u128::MAX, ALICE));
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_deposit(0, &BOB, 100, true),
+				DepositConsequence::Overflow
+			);
+		})
+	}
+
+	#[test]
+	fn can_withdraw() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_withdraw(0, &BOB, 100),
+				WithdrawConsequence::UnknownAsset
+			);
+
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, CHARLIE));
+
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_withdraw(0, &BOB, 100),
+				WithdrawConsequence::Success
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_withdraw(0, &BOB, 95),
+				WithdrawConsequence::WouldDie
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_withdraw(0, &BOB, 150),
+				WithdrawConsequence::NoFunds
+			);
+			assert_eq!(
+				<Assets as Inspect<u64>>::can_withdraw(0, &BOB, 250),
+				WithdrawConsequence::Underflow
+			);
+		})
+	}
+}
+
+mod mutate {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+
+			assert_ok!(<Assets as Mutate<u64>>::mint_into(0, &BOB, 100));
+			assert_eq!(Assets::account(0, BOB), 100);
+			assert_eq!(Assets::asset(0).unwrap().supply, 100);
+
+			assert_eq!(<Assets as Mutate<u64>>::burn_from(0, &BOB, 40), Ok(40));
+			assert_eq!(Assets::account(0, BOB), 60);
+			assert_eq!(Assets::asset(0).unwrap().supply, 60);
+		})
+	}
+
+	#[test]
+	fn must_have_enough_balance() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(<Assets as Mutate<u64>>::mint_into(0, &BOB, 100));
+
+			assert_noop!(
+				<Assets as Mutate<u64>>::burn_from(0, &BOB, 101),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+}
+
+mod transfer {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_eq!(
+				<Assets as Transfer<u64>>::transfer(0, &BOB, &ALICE, 50, true),
+				Ok(50)
+			);
+			assert_eq!(Assets::account(0, BOB), 50);
+			assert_eq!(Assets::account(0, ALICE), 50);
+
+			// The whole balance can only be sent when the account may be reaped.
+			assert_noop!(
+				<Assets as Transfer<u64>>::transfer(0, &BOB, &ALICE, 50, true),
+				Error::<TestRuntime>::BalanceLow
+			);
+			assert_eq!(
+				<Assets as Transfer<u64>>::transfer(0, &BOB, &ALICE, 50, false),
+				Ok(50)
+			);
+			assert_eq!(Assets::account(0, BOB), 0);
+		})
+	}
+
+	#[test]
+	fn recipient_must_reach_min_balance() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			// Nothing is debited from the source when the credit fails.
+			assert_noop!(
+				<Assets as Transfer<u64>>::transfer(0, &BOB, &CHARLIE, 5, false),
+				Error::<TestRuntime>::BalanceLow
+			);
+			assert_eq!(Assets::account(0, BOB), 100);
+		})
+	}
+}
+
+mod create {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<Assets as Create<u64>>::create(3, BOB, false, 10));
+
+			let details = Assets::asset(3).unwrap();
+			assert_eq!(details.owner, BOB);
+			assert_eq!(details.min_balance, 10);
+			assert_eq!(details.deposit, 0);
+
+			assert_noop!(
+				<Assets as Create<u64>>::create(3, ALICE, false, 10),
+				Error::<TestRuntime>::AlreadyExists
+			);
+		})
+	}
+}
@@ -0,0 +1,77 @@
+use crate::{
+	migrations::{v0, v1::MigrateToV1},
+	pallet::Account,
+	tests::mock::*,
+	types::AssetStatus,
+};
+use frame_support::{
+	assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+
+mod migrate_to_v1 {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Assets>();
+			v0::Asset::<TestRuntime>::insert(
+				0,
+				v0::OldAssetDetails {
+					owner: ALICE,
+					supply: 150,
+				},
+			);
+			Account::<TestRuntime>::insert(0, ALICE, 100);
+			Account::<TestRuntime>::insert(0, BOB, 50);
+			// v0 kept the balance of accounts which sent everything they held.
+			Account::<TestRuntime>::insert(0, CHARLIE, 0);
+			v0::Metadata::<TestRuntime>::insert(
+				0,
+				v0::OldAssetMetadata {
+					name: b"asset".to_vec().try_into().unwrap(),
+					symbol: b"AST".to_vec().try_into().unwrap(),
+				},
+			);
+
+			MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+
+			assert_eq!(Assets::on_chain_storage_version(), 1);
+
+			let details = Assets::asset(0).unwrap();
+			assert_eq!(details.owner, ALICE);
+			assert_eq!(details.issuer, ALICE);
+			assert_eq!(details.admin, ALICE);
+			assert_eq!(details.freezer, ALICE);
+			assert_eq!(details.supply, 150);
+			assert_eq!(details.deposit, 0);
+			assert_eq!(details.min_balance, 1);
+			assert_eq!(details.accounts, 2);
+			assert_eq!(details.status, AssetStatus::Live);
+			assert_eq!(Assets::assets_of(ALICE), vec![0]);
+			assert_eq!(Assets::assets_of(BOB), vec![0]);
+			assert!(Assets::assets_of(CHARLIE).is_empty());
+			assert!(!Account::<TestRuntime>::contains_key(0, CHARLIE));
+
+			let metadata = Assets::metadata(0).unwrap();
+			assert_eq!(metadata.deposit, 0);
+			assert_eq!(metadata.name.to_vec(), b"asset".to_vec());
+			assert_eq!(metadata.symbol.to_vec(), b"AST".to_vec());
+			assert_eq!(metadata.decimals, 0);
+			assert!(!metadata.is_frozen);
+		})
+	}
+
+	#[test]
+	fn skipped_when_already_migrated() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Assets>();
+			assert_ok!(Assets::create(Origin::signed(ALICE), 10));
+
+			MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+
+			assert_eq!(Assets::asset(0).unwrap().min_balance, 10);
+		})
+	}
+}
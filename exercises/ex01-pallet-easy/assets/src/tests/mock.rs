@@ -1,5 +1,13 @@
 use crate as pallet_assets;
-use frame_support::parameter_types;
+use crate::{types::AssetId, HandleAssetFee};
+use frame_support::{
+	parameter_types,
+	traits::{
+		tokens::{fungibles, BalanceConversion},
+		ConstU8,
+	},
+	weights::{DispatchClass, IdentityFee},
+};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -17,23 +25,35 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::builder()
+			.base_block(0)
+			.for_class(DispatchClass::all(), |weights| {
+				weights.base_extrinsic = 0;
+			})
+			.for_class(DispatchClass::non_mandatory(), |weights| {
+				weights.max_total = 1024.into();
+			})
+			.build_or_panic();
 	pub const SS58Prefix: u8 = 42;
 }
 
 impl frame_system::Config for TestRuntime {
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u128>;
 	type AccountId = u64;
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockHashCount = BlockHashCount;
 	type BlockLength = ();
 	type BlockNumber = u64;
-	type BlockWeights = ();
+	type BlockWeights = BlockWeights;
 	type Call = Call;
 	type DbWeight = ();
 	type Event = Event;
@@ -54,23 +74,112 @@ impl frame_system::Config for TestRuntime {
 }
 
 parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for TestRuntime {
+	type AccountStore = System;
+	type Balance = u128;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+impl pallet_transaction_payment::Config for TestRuntime {
+	type Event = Event;
+	type FeeMultiplierUpdate = ();
+	type LengthToFee = IdentityFee<u128>;
+	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<u128>;
+}
+
+pub const ASSET_DEPOSIT: u128 = 100;
+pub const METADATA_DEPOSIT_BASE: u128 = 10;
+pub const METADATA_DEPOSIT_PER_BYTE: u128 = 1;
+
+parameter_types! {
+	pub const AssetDeposit: u128 = ASSET_DEPOSIT;
+	pub const MaxBatchSize: u32 = 3;
 	pub const MaxLength: u32 = 20;
+	pub const MetadataDepositBase: u128 = METADATA_DEPOSIT_BASE;
+	pub const MetadataDepositPerByte: u128 = METADATA_DEPOSIT_PER_BYTE;
+}
+
+/// One unit of the native currency is worth `FEE_RATE` units of any asset.
+pub const FEE_RATE: u128 = 2;
+
+pub struct FeeConversion;
+impl BalanceConversion<u128, AssetId, u128> for FeeConversion {
+	type Error = ();
+
+	fn to_asset_balance(balance: u128, asset_id: AssetId) -> Result<u128, ()> {
+		Assets::asset(asset_id).ok_or(())?;
+		Ok(balance * FEE_RATE)
+	}
+}
+
+// Pay the fees to the owner of the asset, or burn them if it cannot receive them.
+pub struct FeesToOwner;
+impl HandleAssetFee for FeesToOwner {
+	fn handle(asset_id: AssetId, amount: u128) {
+		if let Some(details) = Assets::asset(asset_id) {
+			let _ = <Assets as fungibles::Mutate<u64>>::mint_into(asset_id, &details.owner, amount);
+		}
+	}
 }
 
 impl pallet_assets::Config for TestRuntime {
+	type AssetDeposit = AssetDeposit;
+	type Currency = Balances;
 	type Event = Event;
+	type FeeConversion = FeeConversion;
+	type MaxBatchSize = MaxBatchSize;
 	type MaxLength = MaxLength;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type OnAssetFee = FeesToOwner;
+	type WeightInfo = ();
 }
 
+pub const INITIAL_BALANCE: u128 = 1_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let t = frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
+	let mut t = frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
+	pallet_balances::GenesisConfig::<TestRuntime> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
 	let mut ext = sp_io::TestExternalities::new(t);
 	// In order to emit events the block number must be more than 0
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
 
+// Run `test` in a fresh externality, then check that the pallet storage is still consistent.
+pub fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		Assets::do_try_state().unwrap();
+	})
+}
+
 // Mock users AccountId
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+// DAVE has no funds
+pub const DAVE: u64 = 4;
@@ -1,2 +1,6 @@
 pub mod assets;
+pub mod fungibles;
+pub mod migrations;
 pub mod mock;
+pub mod payment;
+pub mod runtime_api;
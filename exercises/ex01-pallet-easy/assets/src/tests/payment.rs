@@ -0,0 +1,218 @@
+use crate::{tests::mock::*, ChargeAssetTxPayment};
+use frame_support::{
+	assert_ok,
+	sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidityError},
+	},
+	weights::{DispatchInfo, PostDispatchInfo},
+};
+
+const LEN: usize = 10;
+
+fn call() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+pub fn last_event() -> Event {
+	frame_system::Pallet::<TestRuntime>::events()
+		.pop()
+		.expect("Event expected")
+		.event
+}
+
+// A call declaring a weight of 100, for a total fee of 110 with the 10 bytes length fee.
+fn info() -> DispatchInfo {
+	DispatchInfo {
+		weight: 100,
+		..Default::default()
+	}
+}
+
+// The call only used half of its declared weight, for an actual fee of 60.
+fn post_info() -> PostDispatchInfo {
+	PostDispatchInfo {
+		actual_weight: Some(50),
+		pays_fee: Default::default(),
+	}
+}
+
+mod charge_asset_tx_payment {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 1_000, BOB));
+
+			let pre = ChargeAssetTxPayment::<TestRuntime>::from(0, Some(0))
+				.pre_dispatch(&BOB, &call(), &info(), LEN)
+				.unwrap();
+			assert_eq!(Assets::account(0, BOB), 1_000 - 110 * FEE_RATE);
+			assert_eq!(Assets::asset(0).unwrap().supply, 1_000 - 110 * FEE_RATE);
+
+			assert_ok!(ChargeAssetTxPayment::<TestRuntime>::post_dispatch(
+				Some(pre),
+				&info(),
+				&post_info(),
+				LEN,
+				&Ok(())
+			));
+			assert_eq!(Assets::account(0, BOB), 1_000 - 60 * FEE_RATE);
+			assert_eq!(Assets::account(0, ALICE), 60 * FEE_RATE);
+			assert_eq!(Assets::asset(0).unwrap().supply, 1_000);
+			assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AssetTxFeePaid {
+					who: BOB,
+					asset_id: 0,
+					actual_fee: 60 * FEE_RATE,
+				})
+			);
+		})
+	}
+
+	#[test]
+	fn refund_is_burned_once_reaped() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 500));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 1_000, BOB));
+
+			let pre = ChargeAssetTxPayment::<TestRuntime>::from(0, Some(0))
+				.pre_dispatch(&BOB, &call(), &info(), LEN)
+				.unwrap();
+			// The call sends the whole remaining balance, reaping the account.
+			assert_ok!(Assets::transfer(
+				Origin::signed(BOB),
+				0,
+				1_000 - 110 * FEE_RATE,
+				CHARLIE
+			));
+
+			// The refund is below `min_balance`, so it cannot open a new account, and neither can
+			// the fee given to the owner.
+			assert_ok!(ChargeAssetTxPayment::<TestRuntime>::post_dispatch(
+				Some(pre),
+				&info(),
+				&post_info(),
+				LEN,
+				&Ok(())
+			));
+			assert_eq!(Assets::account(0, BOB), 0);
+			assert_eq!(Assets::account(0, ALICE), 0);
+			assert_eq!(Assets::asset(0).unwrap().supply, 1_000 - 110 * FEE_RATE);
+			assert_eq!(
+				last_event(),
+				Event::Assets(crate::Event::AssetTxFeePaid {
+					who: BOB,
+					asset_id: 0,
+					actual_fee: 110 * FEE_RATE,
+				})
+			);
+		})
+	}
+
+	#[test]
+	fn native_fallback() {
+		new_test_ext().execute_with(|| {
+			let pre = ChargeAssetTxPayment::<TestRuntime>::from(0, None)
+				.pre_dispatch(&BOB, &call(), &info(), LEN)
+				.unwrap();
+			assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 110);
+
+			assert_ok!(ChargeAssetTxPayment::<TestRuntime>::post_dispatch(
+				Some(pre),
+				&info(),
+				&post_info(),
+				LEN,
+				&Ok(())
+			));
+			assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 60);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				ChargeAssetTxPayment::<TestRuntime>::from(0, Some(0)).validate(
+					&BOB,
+					&call(),
+					&info(),
+					LEN
+				),
+				Err(TransactionValidityError::Invalid(
+					InvalidTransaction::Payment
+				))
+			);
+		})
+	}
+
+	#[test]
+	fn must_have_enough_balance() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(
+				Origin::signed(ALICE),
+				0,
+				110 * FEE_RATE - 1,
+				BOB
+			));
+
+			assert_eq!(
+				ChargeAssetTxPayment::<TestRuntime>::from(0, Some(0)).validate(
+					&BOB,
+					&call(),
+					&info(),
+					LEN
+				),
+				Err(TransactionValidityError::Invalid(
+					InvalidTransaction::Payment
+				))
+			);
+		})
+	}
+
+	#[test]
+	fn must_keep_account_alive() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 110 * FEE_RATE, BOB));
+
+			assert_eq!(
+				ChargeAssetTxPayment::<TestRuntime>::from(0, Some(0)).validate(
+					&BOB,
+					&call(),
+					&info(),
+					LEN
+				),
+				Err(TransactionValidityError::Invalid(
+					InvalidTransaction::Payment
+				))
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_frozen() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 1_000, BOB));
+			assert_ok!(Assets::freeze_account(Origin::signed(ALICE), 0, BOB));
+
+			assert_eq!(
+				ChargeAssetTxPayment::<TestRuntime>::from(0, Some(0)).validate(
+					&BOB,
+					&call(),
+					&info(),
+					LEN
+				),
+				Err(TransactionValidityError::Invalid(
+					InvalidTransaction::Payment
+				))
+			);
+		})
+	}
+}
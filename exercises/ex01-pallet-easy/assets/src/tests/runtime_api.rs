@@ -0,0 +1,124 @@
+use crate::tests::mock::*;
+use frame_support::{assert_ok, BoundedVec};
+
+mod balance_of {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_eq!(Assets::balance_of(0, BOB), 100);
+			assert_eq!(Assets::balance_of(0, CHARLIE), 0);
+			assert_eq!(Assets::balance_of(1, BOB), 0);
+		})
+	}
+}
+
+mod assets_of {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 1, 100, BOB));
+
+			let mut assets = Assets::assets_of(BOB);
+			assets.sort();
+			assert_eq!(assets, vec![0, 1]);
+			assert!(Assets::assets_of(CHARLIE).is_empty());
+		})
+	}
+
+	#[test]
+	fn follows_transfers() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_ok!(Assets::transfer(Origin::signed(BOB), 0, 100, CHARLIE));
+
+			assert!(Assets::assets_of(BOB).is_empty());
+			assert_eq!(Assets::assets_of(CHARLIE), vec![0]);
+		})
+	}
+
+	#[test]
+	fn reaped_on_burn() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+
+			assert_ok!(Assets::burn(Origin::signed(BOB), 0, 100));
+
+			assert!(Assets::assets_of(BOB).is_empty());
+		})
+	}
+
+	#[test]
+	fn cleared_on_destroy() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::start_destroy(Origin::signed(ALICE), 0));
+
+			assert_ok!(Assets::destroy_accounts(Origin::signed(ALICE), 0, 10));
+
+			assert!(Assets::assets_of(BOB).is_empty());
+		})
+	}
+}
+
+mod total_supply {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 100, BOB));
+			assert_ok!(Assets::mint(Origin::signed(ALICE), 0, 50, CHARLIE));
+
+			assert_eq!(Assets::total_supply(0), 150);
+			assert_eq!(Assets::total_supply(1), 0);
+		})
+	}
+}
+
+mod metadata_of {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::create(Origin::signed(ALICE), 1));
+			assert_eq!(Assets::metadata_of(0), None);
+
+			let name: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
+				BoundedVec::try_from("TestAsset".as_bytes().to_vec()).unwrap();
+			let symbol: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
+				BoundedVec::try_from("TASS".as_bytes().to_vec()).unwrap();
+			assert_ok!(Assets::set_metadata(
+				Origin::signed(ALICE),
+				0,
+				name,
+				symbol,
+				18
+			));
+
+			assert_eq!(
+				Assets::metadata_of(0),
+				Some((
+					"TestAsset".as_bytes().to_vec(),
+					"TASS".as_bytes().to_vec(),
+					18
+				))
+			);
+		})
+	}
+}
@@ -8,28 +8,79 @@ use scale_info::TypeInfo;
 
 pub type AssetId = u128;
 
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AssetStatus {
+	/// The asset can be minted, burned and transferred.
+	Live,
+	/// The asset is being destroyed, its accounts are being removed.
+	Destroying,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct AssetDetails<AccountId> {
+pub struct AssetDetails<AccountId, Balance> {
 	pub owner: AccountId,
+	/// Can mint new units of the asset.
+	pub issuer: AccountId,
+	/// Can thaw the asset and frozen accounts.
+	pub admin: AccountId,
+	/// Can freeze the asset and accounts.
+	pub freezer: AccountId,
 	pub supply: u128,
+	/// The amount reserved from the owner when the asset was created.
+	pub deposit: Balance,
+	pub is_frozen: bool,
+	/// The minimum non-zero balance an account can hold.
+	pub min_balance: u128,
+	/// The number of accounts holding some of the asset.
+	pub accounts: u32,
+	pub status: AssetStatus,
 }
 
-impl<AccountId> AssetDetails<AccountId> {
-	pub fn new(owner: AccountId) -> Self {
-		AssetDetails { owner, supply: 0 }
+impl<AccountId: Clone, Balance> AssetDetails<AccountId, Balance> {
+	pub fn new(owner: AccountId, deposit: Balance, min_balance: u128) -> Self {
+		AssetDetails {
+			issuer: owner.clone(),
+			admin: owner.clone(),
+			freezer: owner.clone(),
+			owner,
+			supply: 0,
+			deposit,
+			is_frozen: false,
+			min_balance,
+			accounts: 0,
+			status: AssetStatus::Live,
+		}
 	}
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(S))]
-#[codec(mel_bound())]
-pub struct AssetMetadata<S: Get<u32>> {
+#[codec(mel_bound(Balance: MaxEncodedLen))]
+pub struct AssetMetadata<Balance, S: Get<u32>> {
+	/// The amount reserved from the owner to store this metadata.
+	pub deposit: Balance,
 	pub name: BoundedVec<u8, S>,
 	pub symbol: BoundedVec<u8, S>,
+	/// The number of decimals wallets should use to display balances.
+	pub decimals: u8,
+	/// Whether the metadata is locked against changes by the owner.
+	pub is_frozen: bool,
 }
 
-impl<S: Get<u32>> AssetMetadata<S> {
-	pub fn new(name: BoundedVec<u8, S>, symbol: BoundedVec<u8, S>) -> Self {
-		AssetMetadata { name, symbol }
+impl<Balance, S: Get<u32>> AssetMetadata<Balance, S> {
+	pub fn new(
+		deposit: Balance,
+		name: BoundedVec<u8, S>,
+		symbol: BoundedVec<u8, S>,
+		decimals: u8,
+		is_frozen: bool,
+	) -> Self {
+		AssetMetadata {
+			deposit,
+			name,
+			symbol,
+			decimals,
+			is_frozen,
+		}
 	}
 }
@@ -0,0 +1,341 @@
+//! Weights for pallet_assets.
+//!
+//! THESE VALUES ARE PLACEHOLDERS, NOT BENCHMARK RESULTS. Each function charges the database
+//! accesses listed above it at the runtime `DbWeight`, plus a hand-written execution time, so
+//! that no call is free. They must be replaced with the output of `benchmarking.rs`, run on the
+//! reference hardware by a node including this pallet with the `runtime-benchmarks` feature,
+//! before this pallet is used in production.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_assets.
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn force_create() -> Weight;
+	fn set_metadata(n: u32, s: u32) -> Weight;
+	fn clear_metadata() -> Weight;
+	fn force_set_metadata(n: u32, s: u32) -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn transfer() -> Weight;
+	fn transfer_batch(b: u32) -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn transfer_approved() -> Weight;
+	fn freeze_asset() -> Weight;
+	fn thaw_asset() -> Weight;
+	fn freeze_account() -> Weight;
+	fn thaw_account() -> Weight;
+	fn set_team() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn start_destroy() -> Weight;
+	fn destroy_accounts(c: u32) -> Weight;
+	fn finish_destroy() -> Weight;
+}
+
+/// Weights for pallet_assets using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Assets Nonce (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:0 w:1)
+	fn create() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Nonce (r:1 w:1)
+	fn force_create() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_metadata(n: u32, s: u32) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_metadata() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:1)
+	fn force_set_metadata(n: u32, s: u32) -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets AccountAssets (r:0 w:1)
+	fn mint() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets FrozenAccount (r:1 w:0)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets AccountAssets (r:0 w:1)
+	fn burn() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets FrozenAccount (r:1 w:0)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets AccountAssets (r:0 w:2)
+	fn transfer() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets FrozenAccount (r:1 w:0)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1) per recipient
+	// Storage: Assets AccountAssets (r:0 w:1)
+	// Storage: Assets AccountAssets (r:0 w:1) per recipient
+	fn transfer_batch(b: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((17_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Approvals (r:1 w:1)
+	fn approve_transfer() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Approvals (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Approvals (r:1 w:1)
+	// Storage: Assets FrozenAccount (r:1 w:0)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets AccountAssets (r:0 w:2)
+	fn transfer_approved() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	fn freeze_asset() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	fn thaw_asset() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets FrozenAccount (r:0 w:1)
+	fn freeze_account() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets FrozenAccount (r:0 w:1)
+	fn thaw_account() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	fn set_team() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn transfer_ownership() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:0)
+	// Storage: Assets FrozenAccount (r:1 w:0)
+	// Storage: Assets Approvals (r:0 w:0)
+	// Storage: Assets Account (r:0 w:1) per removed entry
+	// Storage: Assets AccountAssets (r:0 w:1) per removed entry
+	fn destroy_accounts(c: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets FrozenAccount (r:1 w:0)
+	// Storage: Assets Approvals (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn finish_destroy() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn force_create() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_metadata(n: u32, s: u32) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_metadata() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn force_set_metadata(n: u32, s: u32) -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn mint() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn burn() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn transfer() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn transfer_batch(b: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((17_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+	}
+	fn approve_transfer() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_approval() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_approved() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn freeze_asset() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_asset() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_account() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_account() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_team() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_ownership() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn destroy_accounts(c: u32) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn finish_destroy() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
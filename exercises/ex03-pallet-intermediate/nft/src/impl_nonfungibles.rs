@@ -0,0 +1,136 @@
+//! Collections map to `Collection`, whose owner is also their only admin, and items to the
+//! `UniqueAsset`s minted in them. The owner of an item is its sole holder, as returned by
+//! `sole_owner`: items whose supply is split have none, and cannot be burned or transferred
+//! through these traits. When they can, the whole supply moves at once. Items locked by their
+//! creator cannot be transferred, and attributes are the ones of `Attributes`.
+
+use super::*;
+use frame_support::traits::tokens::nonfungibles;
+
+// The details of `item`, if it belongs to `collection`.
+fn asset_in<T: Config>(
+	collection: &CollectionId,
+	item: &UniqueAssetId,
+) -> Option<UniqueAssetDetails<T, T::MaxLength>> {
+	Pallet::<T>::unique_asset(item).filter(|details| details.collection_id() == *collection)
+}
+
+impl<T: Config> nonfungibles::Inspect<T::AccountId> for Pallet<T> {
+	type CollectionId = CollectionId;
+	type ItemId = UniqueAssetId;
+
+	fn owner(collection: &CollectionId, item: &UniqueAssetId) -> Option<T::AccountId> {
+		asset_in::<T>(collection, item)?;
+		Self::sole_owner(*item)
+	}
+
+	fn collection_owner(collection: &CollectionId) -> Option<T::AccountId> {
+		Collection::<T>::get(collection).map(|details| details.owner())
+	}
+
+	fn attribute(collection: &CollectionId, item: &UniqueAssetId, key: &[u8]) -> Option<Vec<u8>> {
+		asset_in::<T>(collection, item)?;
+		let key: BoundedVec<u8, T::KeyLimit> = key.to_vec().try_into().ok()?;
+		Attributes::<T>::get(item, key).map(|value| value.into_inner())
+	}
+
+	fn can_transfer(collection: &CollectionId, item: &UniqueAssetId) -> bool {
+		matches!(asset_in::<T>(collection, item), Some(details) if details.transferable)
+			&& Self::sole_owner(*item).is_some()
+	}
+}
+
+impl<T: Config> nonfungibles::Create<T::AccountId> for Pallet<T> {
+	/// Create `collection`, owned by `who`. Collections have no separate admin, so `admin` is
+	/// ignored.
+	///
+	/// Collection IDs are assigned in sequence: `collection` must be the next one.
+	fn create_collection(
+		collection: &CollectionId,
+		who: &T::AccountId,
+		_admin: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			*collection == Self::collection_nonce(),
+			Error::<T>::UnavailableId
+		);
+
+		Self::do_create_collection(who.clone(), None, Default::default());
+		Ok(())
+	}
+}
+
+impl<T: Config> nonfungibles::Mutate<T::AccountId> for Pallet<T> {
+	/// Mint `item` in `collection` with a supply of 1 and no metadata, owned by `who`. The
+	/// collection owner is the creator of the asset.
+	///
+	/// Asset IDs are shared by all collections: `item` must be the next one overall.
+	fn mint_into(
+		collection: &CollectionId,
+		item: &UniqueAssetId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(*item == Self::nonce(), Error::<T>::UnavailableId);
+		let creator = Self::collection(collection).ok_or(Error::<T>::UnknownCollection)?.owner();
+
+		let details = UniqueAssetDetails::<T, T::MaxLength>::new(
+			*collection,
+			creator,
+			Default::default(),
+			None,
+			1,
+			true,
+		);
+		Self::do_mint(details, who.clone())?;
+		Ok(())
+	}
+
+	fn burn(
+		collection: &CollectionId,
+		item: &UniqueAssetId,
+		maybe_check_owner: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let details = asset_in::<T>(collection, item).ok_or(Error::<T>::UnknownAssetId)?;
+		let owner = Self::sole_owner(*item).ok_or(Error::<T>::NoSoleOwner)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(*check_owner == owner, Error::<T>::NotOwned);
+		}
+
+		Self::do_burn(*item, owner, details.supply)
+	}
+
+	fn set_attribute(
+		collection: &CollectionId,
+		item: &UniqueAssetId,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		asset_in::<T>(collection, item).ok_or(Error::<T>::UnknownAssetId)?;
+		let key: BoundedVec<u8, T::KeyLimit> =
+			key.to_vec().try_into().map_err(|_| Error::<T>::AttributeTooLong)?;
+		let value: BoundedVec<u8, T::ValueLimit> =
+			value.to_vec().try_into().map_err(|_| Error::<T>::AttributeTooLong)?;
+
+		Attributes::<T>::insert(item, key.clone(), value.clone());
+
+		Self::deposit_event(Event::AttributeSet {
+			asset_id: *item,
+			key,
+			value,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config> nonfungibles::Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		collection: &CollectionId,
+		item: &UniqueAssetId,
+		destination: &T::AccountId,
+	) -> DispatchResult {
+		let details = asset_in::<T>(collection, item).ok_or(Error::<T>::UnknownAssetId)?;
+		let owner = Self::sole_owner(*item).ok_or(Error::<T>::NoSoleOwner)?;
+
+		Self::do_transfer(*item, owner, destination.clone(), details.supply)
+	}
+}
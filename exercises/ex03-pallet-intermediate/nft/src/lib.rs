@@ -5,19 +5,29 @@
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
 
+mod impl_nonfungibles;
 #[cfg(test)]
 mod tests;
 pub mod types;
 
-use frame_support::ensure;
-use std::cmp;
+use codec::Encode;
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	sp_runtime::{traits::Zero, DispatchError},
+	BoundedVec,
+};
+use sp_std::vec::Vec;
 use types::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_support::sp_runtime;
+	use frame_support::sp_runtime::{
+		traits::{Hash, IdentifyAccount, Verify},
+		Perbill,
+	};
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::config]
@@ -26,6 +36,28 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
+
+		/// The highest royalty rate a creator can set on an asset.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+
+		/// The number of past metadata kept in `MetadataHistory` for each asset.
+		#[pallet::constant]
+		type MaxMetadataHistory: Get<u32>;
+
+		/// The maximum length of an attribute key.
+		#[pallet::constant]
+		type KeyLimit: Get<u32>;
+
+		/// The maximum length of an attribute value.
+		#[pallet::constant]
+		type ValueLimit: Get<u32>;
+
+		/// The signature of a mint voucher, usually `sp_runtime::MultiSignature`.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key of a creator signing mint vouchers.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
 	}
 
 	#[pallet::pallet]
@@ -50,17 +82,101 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// The assets a specific account holds some of.
+	pub(super) type OwnedAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		UniqueAssetId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	/// Amount of an asset that an operator can transfer on behalf of its owner.
+	pub(super) type Approvals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, UniqueAssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // owner
+			NMapKey<Blake2_128Concat, T::AccountId>, // operator
+		),
+		u128,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn nonce)]
 	/// Nonce for id of the next created asset
 	pub(super) type Nonce<T: Config> = StorageValue<_, UniqueAssetId, ValueQuery>;
 
+	#[pallet::storage]
+	/// The voucher nonces already used by a specific creator.
+	pub(super) type UsedVoucherNonces<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, u64, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_history)]
+	/// The block and hash of the last metadata replaced on a specific asset, oldest first.
+	pub(super) type MetadataHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		UniqueAssetId,
+		BoundedVec<(T::BlockNumber, T::Hash), T::MaxMetadataHistory>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attribute)]
+	/// Attributes of a specific asset, set by its creator.
+	pub(super) type Attributes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		UniqueAssetId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::KeyLimit>,
+		BoundedVec<u8, T::ValueLimit>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection)]
+	/// Details of a collection of unique assets.
+	pub(super) type Collection<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, CollectionDetails<T, T::MaxLength>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_assets)]
+	/// The assets minted in a specific collection.
+	pub(super) type CollectionAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		UniqueAssetId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_nonce)]
+	/// Nonce for id of the next created collection
+	pub(super) type CollectionNonce<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
+		/// New collection created
+		CollectionCreated {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+		},
 		/// New unique asset created
 		Created {
 			creator: T::AccountId,
+			collection_id: CollectionId,
 			asset_id: UniqueAssetId,
 		},
 		/// Some assets have been burned
@@ -76,6 +192,47 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: u128,
 		},
+		/// An operator has been allowed to transfer some assets on behalf of their owner
+		Approved {
+			asset_id: UniqueAssetId,
+			owner: T::AccountId,
+			operator: T::AccountId,
+			amount: u128,
+		},
+		/// The whole supply of an asset has been burned to create a new single-supply asset
+		Redeemed {
+			asset_id: UniqueAssetId,
+			new_asset_id: UniqueAssetId,
+			owner: T::AccountId,
+		},
+		/// The supply of an asset held by a single account has been replaced
+		Fractionalized {
+			asset_id: UniqueAssetId,
+			owner: T::AccountId,
+			supply: u128,
+		},
+		/// An asset can no longer be transferred
+		Locked { asset_id: UniqueAssetId },
+		/// An asset can be transferred again
+		Unlocked { asset_id: UniqueAssetId },
+		/// The metadata of an asset has been replaced
+		MetadataUpdated {
+			asset_id: UniqueAssetId,
+			metadata: BoundedVec<u8, T::MaxLength>,
+		},
+		/// The metadata of an asset can no longer be updated
+		MetadataFrozen { asset_id: UniqueAssetId },
+		/// An attribute has been set on an asset
+		AttributeSet {
+			asset_id: UniqueAssetId,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: BoundedVec<u8, T::ValueLimit>,
+		},
+		/// An attribute has been removed from an asset
+		AttributeCleared {
+			asset_id: UniqueAssetId,
+			key: BoundedVec<u8, T::KeyLimit>,
+		},
 	}
 
 	#[pallet::error]
@@ -86,33 +243,142 @@ pub mod pallet {
 		NotOwned,
 		/// Supply must be positive
 		NoSupply,
+		/// The collection ID is unknown
+		UnknownCollection,
+		/// Only the collection owner can mint in it
+		NotCollectionOwner,
+		/// The collection already holds its maximum number of assets
+		CollectionFull,
+		/// Only the asset creator can change its attributes
+		NotCreator,
+		/// The asset has no attribute with this key
+		UnknownAttribute,
+		/// The royalty rate is above `MaxRoyalty`
+		RoyaltyTooHigh,
+		/// The account does not own enough of this asset
+		InsufficientBalance,
+		/// The operator is not allowed to transfer this amount on behalf of the owner
+		Unapproved,
+		/// The asset is locked and cannot be transferred
+		Locked,
+		/// The metadata of the asset is frozen
+		MetadataFrozen,
+		/// The ID is not the next one to be assigned
+		UnavailableId,
+		/// The asset is held by several accounts
+		NoSoleOwner,
+		/// The attribute key or value is too long
+		AttributeTooLong,
+		/// The voucher is not signed by its creator
+		InvalidSignature,
+		/// The voucher expiry block has passed
+		VoucherExpired,
+		/// The voucher nonce has already been used by its creator
+		VoucherUsed,
+		/// The amount must be positive
+		ZeroAmount,
+		/// The voucher can only be used by its beneficiary
+		NotBeneficiary,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		#[pallet::weight(0)]
+		pub fn create_collection(
+			origin: OriginFor<T>,
+			max_items: Option<u32>,
+			metadata: BoundedVec<u8, T::MaxLength>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Self::do_create_collection(origin, max_items, metadata);
+			Ok(())
+		}
+
 		#[pallet::weight(0)]
 		pub fn mint(
 			origin: OriginFor<T>,
+			collection_id: CollectionId,
 			metadata: BoundedVec<u8, T::MaxLength>,
 			supply: u128,
+			royalty: Option<Royalty<T::AccountId>>,
+			transferable: bool,
 		) -> DispatchResult {
 			let origin_account_id = ensure_signed(origin)?;
 			if supply <= 0 {
 				return Err(DispatchError::from(Error::<T>::NoSupply));
 			}
-			let asset_id: UniqueAssetId = Nonce::<T>::get();
-			Nonce::<T>::set(asset_id + 1);
+			if let Some(royalty) = &royalty {
+				ensure!(
+					royalty.rate <= T::MaxRoyalty::get(),
+					Error::<T>::RoyaltyTooHigh
+				);
+			}
+			let collection =
+				Self::collection(collection_id).ok_or(Error::<T>::UnknownCollection)?;
+			ensure!(
+				collection.owner() == origin_account_id,
+				Error::<T>::NotCollectionOwner
+			);
+
 			let asset_details = UniqueAssetDetails::<T, T::MaxLength>::new(
+				collection_id,
 				origin_account_id.clone(),
 				metadata,
+				royalty,
 				supply,
+				transferable,
 			);
-			UniqueAsset::<T>::set(asset_id, Some(asset_details));
-			Account::<T>::set(asset_id, origin_account_id.clone(), supply);
-			Self::deposit_event(Event::<T>::Created {
-				creator: origin_account_id,
-				asset_id,
-			});
+			Self::do_mint(asset_details, origin_account_id)?;
+			Ok(())
+		}
+
+		/// Mint an asset authorised off-chain by `voucher.creator`, crediting its whole supply to
+		/// the caller. `signature` must be the signature of `voucher_payload(voucher)` by the
+		/// creator, who must own the collection. When the voucher names a beneficiary, only they
+		/// can use it.
+		// Verifying the signature takes about 50µs, on top of the storage accesses of a mint.
+		#[pallet::weight(50_000_000 + T::DbWeight::get().reads_writes(4, 7))]
+		pub fn mint_with_voucher(
+			origin: OriginFor<T>,
+			voucher: MintVoucher<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			if let Some(beneficiary) = &voucher.beneficiary {
+				ensure!(*beneficiary == origin, Error::<T>::NotBeneficiary);
+			}
+			ensure!(
+				signature.verify(&Self::voucher_payload(&voucher)[..], &voucher.creator),
+				Error::<T>::InvalidSignature
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= voucher.expiry,
+				Error::<T>::VoucherExpired
+			);
+			ensure!(
+				!UsedVoucherNonces::<T>::contains_key(&voucher.creator, voucher.nonce),
+				Error::<T>::VoucherUsed
+			);
+			ensure!(voucher.supply > 0, Error::<T>::NoSupply);
+
+			let collection =
+				Self::collection(voucher.collection_id).ok_or(Error::<T>::UnknownCollection)?;
+			ensure!(
+				collection.owner() == voucher.creator,
+				Error::<T>::NotCollectionOwner
+			);
+
+			let asset_details = UniqueAssetDetails::<T, T::MaxLength>::new(
+				voucher.collection_id,
+				voucher.creator.clone(),
+				voucher.metadata,
+				None,
+				voucher.supply,
+				true,
+			);
+			Self::do_mint(asset_details, origin)?;
+			UsedVoucherNonces::<T>::insert(voucher.creator, voucher.nonce, ());
 			Ok(())
 		}
 
@@ -121,71 +387,461 @@ pub mod pallet {
 			// - Ensure the extrinsic origin is a signed transaction.
 			let origin = ensure_signed(origin)?;
 
-			// - Mutate the total supply.
-			let old_supply = Account::<T>::get(asset_id, origin.clone());
-			let burned_amount = cmp::min(amount, old_supply);
+			// - Mutate the total supply and the account balance by exactly `amount`.
+			Self::do_burn(asset_id, origin, amount)
+		}
+
+		#[pallet::weight(0)]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			asset_id: UniqueAssetId,
+			amount: u128,
+			to: T::AccountId,
+		) -> DispatchResult {
+			// - Ensure the extrinsic origin is a signed transaction.
+			let origin = ensure_signed(origin)?;
+
+			Self::do_transfer(asset_id, origin, to, amount)
+		}
+
+		/// Allow `operator` to transfer up to `amount` of `asset_id` on behalf of the caller.
+		#[pallet::weight(0)]
+		pub fn approve(
+			origin: OriginFor<T>,
+			asset_id: UniqueAssetId,
+			operator: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(
+				Self::unique_asset(asset_id).is_some(),
+				Error::<T>::UnknownAssetId
+			);
+
+			Approvals::<T>::insert((asset_id, owner.clone(), operator.clone()), amount);
+
+			Self::deposit_event(Event::Approved {
+				asset_id,
+				owner,
+				operator,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Transfer `amount` of `asset_id` from `from` to `to`, within the allowance `from` gave
+		/// to the caller.
+		#[pallet::weight(0)]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			asset_id: UniqueAssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			Approvals::<T>::try_mutate_exists(
+				(asset_id, from.clone(), operator),
+				|maybe_allowance| -> DispatchResult {
+					let allowance = maybe_allowance.ok_or(Error::<T>::Unapproved)?;
+					ensure!(allowance >= amount, Error::<T>::Unapproved);
+
+					Self::do_transfer(asset_id, from, to, amount)?;
+
+					*maybe_allowance = Some(allowance - amount).filter(|remaining| *remaining > 0);
+					Ok(())
+				},
+			)
+		}
+
+		/// Burn the whole supply of `asset_id` to create a new asset with a supply of 1, owned by
+		/// the caller. The caller must hold the whole supply.
+		///
+		/// The new asset keeps the collection, creator, metadata, royalty, transferability,
+		/// attributes and metadata history of the redeemed one. It replaces it in the collection,
+		/// so it does not count against `max_items`. The redeemed asset is removed, along with
+		/// its approvals.
+		#[pallet::weight(0)]
+		pub fn redeem(origin: OriginFor<T>, asset_id: UniqueAssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Self::unique_asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+			Self::ensure_sole_owner(asset_id, &details, &origin)?;
+
+			Self::do_burn(asset_id, origin.clone(), details.supply)?;
+
+			let mut new_details = UniqueAssetDetails::<T, T::MaxLength>::new(
+				details.collection_id(),
+				details.creator(),
+				details.metadata(),
+				details.royalty(),
+				1,
+				details.transferable,
+			);
+			new_details.metadata_frozen = details.metadata_frozen;
+			let new_asset_id = Self::insert_asset(new_details, origin.clone());
+
+			UniqueAsset::<T>::remove(asset_id);
+			CollectionAssets::<T>::remove(details.collection_id(), asset_id);
+			for _ in Approvals::<T>::drain_prefix((asset_id,)) {}
+			let attributes: Vec<_> = Attributes::<T>::drain_prefix(asset_id).collect();
+			for (key, value) in attributes {
+				Attributes::<T>::insert(new_asset_id, key, value);
+			}
+			let history = MetadataHistory::<T>::take(asset_id);
+			if !history.is_empty() {
+				MetadataHistory::<T>::insert(new_asset_id, history);
+			}
+
+			Self::deposit_event(Event::Redeemed {
+				asset_id,
+				new_asset_id,
+				owner: origin,
+			});
+			Ok(())
+		}
+
+		/// Split `asset_id` into `new_supply` fractions, or merge its fractions, all held by the
+		/// caller. The caller must hold the whole supply.
+		#[pallet::weight(0)]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			asset_id: UniqueAssetId,
+			new_supply: u128,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			ensure!(new_supply > 0, Error::<T>::NoSupply);
+
 			UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
 				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
-				details.supply -= burned_amount;
+				Self::ensure_sole_owner(asset_id, details, &origin)?;
+
+				details.supply = new_supply;
+				Account::<T>::insert(asset_id, origin.clone(), new_supply);
 				Ok(())
 			})?;
-			if old_supply == 0 {
-				return Err(DispatchError::from(Error::<T>::NotOwned));
-			}
-			// - Mutate the account balance.
-			Account::<T>::mutate(asset_id, origin.clone(), |balance| {
-				*balance = balance.saturating_sub(amount);
-			});
 
-			Self::deposit_event(Event::Burned {
+			Self::deposit_event(Event::Fractionalized {
 				asset_id,
 				owner: origin,
-				total_supply: old_supply - burned_amount,
+				supply: new_supply,
 			});
 			Ok(())
 		}
 
+		/// Prevent `asset_id` from being transferred. Only callable by the creator.
 		#[pallet::weight(0)]
-		pub fn transfer(
+		pub fn lock(origin: OriginFor<T>, asset_id: UniqueAssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_creator(asset_id, origin)?;
+
+			UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				details.transferable = false;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Locked { asset_id });
+			Ok(())
+		}
+
+		/// Allow `asset_id` to be transferred again. Only callable by the creator.
+		#[pallet::weight(0)]
+		pub fn unlock(origin: OriginFor<T>, asset_id: UniqueAssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_creator(asset_id, origin)?;
+
+			UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				details.transferable = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Unlocked { asset_id });
+			Ok(())
+		}
+
+		/// Replace the metadata of `asset_id`, recording the hash of the previous one. Only callable
+		/// by the creator, until the metadata is frozen.
+		#[pallet::weight(0)]
+		pub fn update_metadata(
 			origin: OriginFor<T>,
 			asset_id: UniqueAssetId,
-			amount: u128,
-			to: T::AccountId,
+			metadata: BoundedVec<u8, T::MaxLength>,
 		) -> DispatchResult {
-			// TODO:
-			// - Ensure the extrinsic origin is a signed transaction.
 			let origin = ensure_signed(origin)?;
+			Self::ensure_is_creator(asset_id, origin)?;
+
+			let old_metadata = UniqueAsset::<T>::try_mutate(
+				asset_id,
+				|maybe_details| -> Result<_, DispatchError> {
+					let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+					ensure!(!details.metadata_frozen, Error::<T>::MetadataFrozen);
+					Ok(details.set_metadata(metadata.clone()))
+				},
+			)?;
 
-			if let Some(_) = UniqueAsset::<T>::get(asset_id) {
-				// - Mutate both account balances.
-				let old_supply = Account::<T>::get(asset_id, origin.clone());
-				if old_supply <= 0 {
-					return Err(DispatchError::from(Error::<T>::NotOwned));
+			let entry = (
+				frame_system::Pallet::<T>::block_number(),
+				T::Hashing::hash(&old_metadata[..]),
+			);
+			MetadataHistory::<T>::mutate(asset_id, |history| {
+				// Drop the oldest entry once the history is full.
+				if !history.is_empty() && history.len() as u32 >= T::MaxMetadataHistory::get() {
+					history.remove(0);
 				}
-				let transferred_amount = cmp::min(amount, old_supply);
-
-				Account::<T>::mutate(asset_id, origin.clone(), |balance| {
-					*balance -= transferred_amount;
-				});
-
-				Account::<T>::mutate(asset_id, to.clone(), |balance| {
-					*balance += transferred_amount;
-				});
-				// - Emit a `Transferred` event.
-				Self::deposit_event(Event::Transferred {
-					asset_id,
-					from: origin,
-					to,
-					amount: transferred_amount,
-				});
+				let _ = history.try_push(entry);
+			});
+
+			Self::deposit_event(Event::MetadataUpdated { asset_id, metadata });
+			Ok(())
+		}
+
+		/// Prevent any further update of the metadata of `asset_id`. Only callable by the creator.
+		#[pallet::weight(0)]
+		pub fn freeze_metadata(origin: OriginFor<T>, asset_id: UniqueAssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_creator(asset_id, origin)?;
+
+			UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+				details.metadata_frozen = true;
 				Ok(())
+			})?;
+
+			Self::deposit_event(Event::MetadataFrozen { asset_id });
+			Ok(())
+		}
+
+		#[pallet::weight(0)]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			asset_id: UniqueAssetId,
+			key: BoundedVec<u8, T::KeyLimit>,
+			value: BoundedVec<u8, T::ValueLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_creator(asset_id, origin)?;
+
+			Attributes::<T>::insert(asset_id, key.clone(), value.clone());
+
+			Self::deposit_event(Event::AttributeSet {
+				asset_id,
+				key,
+				value,
+			});
+			Ok(())
+		}
+
+		#[pallet::weight(0)]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			asset_id: UniqueAssetId,
+			key: BoundedVec<u8, T::KeyLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_is_creator(asset_id, origin)?;
+
+			Attributes::<T>::take(asset_id, key.clone()).ok_or(Error::<T>::UnknownAttribute)?;
+
+			Self::deposit_event(Event::AttributeCleared { asset_id, key });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account to pay on a sale of `asset_id` for `sale_price`, and the amount it is owed.
+	pub fn royalty_info(asset_id: UniqueAssetId, sale_price: u128) -> Option<(T::AccountId, u128)> {
+		let royalty = Self::unique_asset(asset_id)?.royalty()?;
+		Some((royalty.beneficiary, royalty.rate.mul_floor(sale_price)))
+	}
+
+	// Create a collection owned by `owner`, returning its ID.
+	fn do_create_collection(
+		owner: T::AccountId,
+		max_items: Option<u32>,
+		metadata: BoundedVec<u8, T::MaxLength>,
+	) -> CollectionId {
+		let collection_id: CollectionId = CollectionNonce::<T>::get();
+		CollectionNonce::<T>::set(collection_id + 1);
+		Collection::<T>::insert(
+			collection_id,
+			CollectionDetails::<T, T::MaxLength>::new(owner.clone(), metadata, max_items),
+		);
+
+		Self::deposit_event(Event::<T>::CollectionCreated {
+			owner,
+			collection_id,
+		});
+		collection_id
+	}
+
+	// Register a new asset in its collection and credit its whole supply to `owner`, returning
+	// its ID.
+	fn do_mint(
+		details: UniqueAssetDetails<T, T::MaxLength>,
+		owner: T::AccountId,
+	) -> Result<UniqueAssetId, DispatchError> {
+		let collection_id = details.collection_id();
+		Collection::<T>::try_mutate(collection_id, |maybe_collection| -> DispatchResult {
+			let collection = maybe_collection.as_mut().ok_or(Error::<T>::UnknownCollection)?;
+			if let Some(max_items) = collection.max_items {
+				ensure!(collection.items < max_items, Error::<T>::CollectionFull);
+			}
+			collection.items += 1;
+			Ok(())
+		})?;
+
+		Ok(Self::insert_asset(details, owner))
+	}
+
+	// Store a new asset and credit its whole supply to `owner`, returning its ID. Does not check
+	// the capacity of the collection.
+	fn insert_asset(
+		details: UniqueAssetDetails<T, T::MaxLength>,
+		owner: T::AccountId,
+	) -> UniqueAssetId {
+		let collection_id = details.collection_id();
+		let asset_id: UniqueAssetId = Nonce::<T>::get();
+		Nonce::<T>::set(asset_id + 1);
+		let creator = details.creator();
+		let supply = details.supply;
+		UniqueAsset::<T>::set(asset_id, Some(details));
+		CollectionAssets::<T>::insert(collection_id, asset_id, ());
+		Self::increase_balance(asset_id, owner, supply);
+		Self::deposit_event(Event::<T>::Created {
+			creator,
+			collection_id,
+			asset_id,
+		});
+		asset_id
+	}
+
+	// Destroy exactly `amount` of `asset_id` held by `owner`.
+	fn do_burn(asset_id: UniqueAssetId, owner: T::AccountId, amount: u128) -> DispatchResult {
+		let mut total_supply = 0;
+		UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAssetId)?;
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			Self::decrease_balance(asset_id, owner.clone(), amount)?;
+			details.supply -= amount;
+			total_supply = details.supply;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::Burned {
+			asset_id,
+			owner,
+			total_supply,
+		});
+		Ok(())
+	}
+
+	// Move exactly `amount` of `asset_id` from `from` to `to`.
+	fn do_transfer(
+		asset_id: UniqueAssetId,
+		from: T::AccountId,
+		to: T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		let details = Self::unique_asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		ensure!(details.transferable, Error::<T>::Locked);
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+		Self::decrease_balance(asset_id, from.clone(), amount)?;
+		Self::increase_balance(asset_id, to.clone(), amount);
+
+		Self::deposit_event(Event::Transferred {
+			asset_id,
+			from,
+			to,
+			amount,
+		});
+		Ok(())
+	}
+
+	// Credit `amount` to the balance of `who`, indexing the asset as owned by `who`.
+	fn increase_balance(asset_id: UniqueAssetId, who: T::AccountId, amount: u128) {
+		// Do not index an asset `who` does not hold.
+		if amount == 0 {
+			return;
+		}
+		Account::<T>::mutate(asset_id, who.clone(), |balance| {
+			*balance += amount;
+		});
+		OwnedAssets::<T>::insert(who, asset_id, ());
+	}
+
+	// Debit exactly `amount` from the balance of `who`, dropping the asset from the assets owned
+	// by `who` once nothing is left.
+	fn decrease_balance(
+		asset_id: UniqueAssetId,
+		who: T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		Account::<T>::try_mutate_exists(asset_id, who.clone(), |maybe_balance| -> DispatchResult {
+			let balance =
+				maybe_balance.filter(|balance| *balance > 0).ok_or(Error::<T>::NotOwned)?;
+			let remaining = balance.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+
+			if remaining == 0 {
+				*maybe_balance = None;
+				OwnedAssets::<T>::remove(&who, asset_id);
 			} else {
-				Err(DispatchError::Module(sp_runtime::ModuleError {
-					index: 1,
-					error: [0, 0, 0, 0],
-					message: Some("UnknownAssetId"),
-				}))
+				*maybe_balance = Some(remaining);
 			}
+			Ok(())
+		})
+	}
+
+	/// The assets `owner` holds some of.
+	pub fn assets_of(owner: T::AccountId) -> Vec<UniqueAssetId> {
+		OwnedAssets::<T>::iter_key_prefix(owner).collect()
+	}
+
+	/// The accounts holding some of `asset_id`, with their balance.
+	pub fn holders_of(asset_id: UniqueAssetId) -> Vec<(T::AccountId, u128)> {
+		Account::<T>::iter_prefix(asset_id)
+			.filter(|(_, balance)| *balance > 0)
+			.collect()
+	}
+
+	/// The account holding the whole supply of `asset_id`, if it is the only holder.
+	pub fn sole_owner(asset_id: UniqueAssetId) -> Option<T::AccountId> {
+		// Stop at the second holder rather than reading all of them.
+		let mut holders = Account::<T>::iter_prefix(asset_id).filter(|(_, balance)| *balance > 0);
+		match (holders.next(), holders.next()) {
+			(Some((owner, _)), None) => Some(owner),
+			_ => None,
 		}
 	}
+
+	/// The bytes a creator signs to authorise `voucher`. They are bound to this pallet and to the
+	/// genesis hash of the chain, so that the signature cannot be used anywhere else.
+	pub fn voucher_payload(voucher: &MintVoucher<T>) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		(b"pallet-nft/voucher", genesis_hash, voucher).encode()
+	}
+
+	// Ensure `who` holds the whole supply of `asset_id`.
+	// As balances add up to the supply, this does not need to look at the other holders.
+	fn ensure_sole_owner(
+		asset_id: UniqueAssetId,
+		details: &UniqueAssetDetails<T, T::MaxLength>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		match Self::account(asset_id, who) {
+			0 => Err(Error::<T>::NotOwned.into()),
+			balance if balance == details.supply => Ok(()),
+			_ => Err(Error::<T>::NoSoleOwner.into()),
+		}
+	}
+
+	fn ensure_is_creator(asset_id: UniqueAssetId, account: T::AccountId) -> DispatchResult {
+		let details = Self::unique_asset(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		ensure!(details.creator() == account, Error::<T>::NotCreator);
+
+		Ok(())
+	}
 }
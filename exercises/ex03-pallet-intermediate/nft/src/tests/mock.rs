@@ -2,8 +2,9 @@ use crate as pallet_nft;
 use frame_support::parameter_types;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
@@ -54,12 +55,22 @@ impl frame_system::Config for TestRuntime {
 }
 
 parameter_types! {
+	pub const KeyLimit: u32 = 10;
 	pub const MaxLength: u32 = 20;
+	pub const MaxMetadataHistory: u32 = 2;
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(20);
+	pub const ValueLimit: u32 = 20;
 }
 
 impl pallet_nft::Config for TestRuntime {
 	type Event = Event;
+	type KeyLimit = KeyLimit;
 	type MaxLength = MaxLength;
+	type MaxMetadataHistory = MaxMetadataHistory;
+	type MaxRoyalty = MaxRoyalty;
+	type OffchainPublic = UintAuthorityId;
+	type OffchainSignature = TestSignature;
+	type ValueLimit = ValueLimit;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -74,3 +85,4 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 // Mock users AccountId
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
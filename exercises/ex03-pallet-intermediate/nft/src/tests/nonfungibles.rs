@@ -0,0 +1,278 @@
+use crate::{tests::mock::*, Error};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
+};
+
+fn create_collection_and_mint(supply: u128) {
+	assert_ok!(NFTs::create_collection(
+		Origin::signed(ALICE),
+		None,
+		"Some collection".as_bytes().to_vec().try_into().unwrap()
+	));
+	assert_ok!(NFTs::mint(
+		Origin::signed(ALICE),
+		0,
+		"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+		supply,
+		None,
+		true
+	));
+}
+
+mod inspect {
+	use super::*;
+
+	#[test]
+	fn sole_holder_is_owner() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &0), Some(ALICE));
+			assert_eq!(<NFTs as Inspect<u64>>::collection_owner(&0), Some(ALICE));
+			assert!(<NFTs as Inspect<u64>>::can_transfer(&0, &0));
+
+			// The whole supply moved to another account.
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 5, BOB));
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &0), Some(BOB));
+		})
+	}
+
+	#[test]
+	fn split_asset_has_no_owner() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &0), None);
+			assert!(!<NFTs as Inspect<u64>>::can_transfer(&0, &0));
+		})
+	}
+
+	#[test]
+	fn locked_asset_cannot_be_transferred() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(NFTs::lock(Origin::signed(ALICE), 0));
+
+			assert!(!<NFTs as Inspect<u64>>::can_transfer(&0, &0));
+		})
+	}
+
+	#[test]
+	fn attribute() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(NFTs::set_attribute(
+				Origin::signed(ALICE),
+				0,
+				"color".as_bytes().to_vec().try_into().unwrap(),
+				"blue".as_bytes().to_vec().try_into().unwrap()
+			));
+
+			assert_eq!(
+				<NFTs as Inspect<u64>>::attribute(&0, &0, "color".as_bytes()),
+				Some("blue".as_bytes().to_vec())
+			);
+			assert_eq!(
+				<NFTs as Inspect<u64>>::attribute(&0, &0, "size".as_bytes()),
+				None
+			);
+		})
+	}
+
+	#[test]
+	fn item_must_be_in_collection() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&1, &0), None);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &1), None);
+			assert_eq!(<NFTs as Inspect<u64>>::collection_owner(&1), None);
+			assert!(!<NFTs as Inspect<u64>>::can_transfer(&1, &0));
+		})
+	}
+}
+
+mod create {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<NFTs as Create<u64>>::create_collection(&0, &ALICE, &BOB));
+
+			assert_eq!(NFTs::collection_nonce(), 1);
+			assert_eq!(NFTs::collection(0).unwrap().owner(), ALICE);
+		})
+	}
+
+	#[test]
+	fn id_must_be_next() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<NFTs as Create<u64>>::create_collection(&1, &ALICE, &ALICE),
+				Error::<TestRuntime>::UnavailableId
+			);
+		})
+	}
+}
+
+mod mutate {
+	use super::*;
+
+	#[test]
+	fn mint_into() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<NFTs as Create<u64>>::create_collection(&0, &ALICE, &ALICE));
+			assert_ok!(<NFTs as Mutate<u64>>::mint_into(&0, &0, &BOB));
+
+			let details = NFTs::unique_asset(0).unwrap();
+			assert_eq!(details.creator(), ALICE);
+			assert_eq!(details.supply, 1);
+			assert_eq!(NFTs::account(0, BOB), 1);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &0), Some(BOB));
+		})
+	}
+
+	#[test]
+	fn mint_into_must_use_next_id() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<NFTs as Create<u64>>::create_collection(&0, &ALICE, &ALICE));
+			assert_noop!(
+				<NFTs as Mutate<u64>>::mint_into(&0, &1, &BOB),
+				Error::<TestRuntime>::UnavailableId
+			);
+		})
+	}
+
+	#[test]
+	fn mint_into_must_have_collection() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<NFTs as Mutate<u64>>::mint_into(&0, &0, &BOB),
+				Error::<TestRuntime>::UnknownCollection
+			);
+		})
+	}
+
+	#[test]
+	fn burn() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(<NFTs as Mutate<u64>>::burn(&0, &0, Some(&ALICE)));
+
+			assert_eq!(NFTs::unique_asset(0).unwrap().supply, 0);
+			assert_eq!(NFTs::account(0, ALICE), 0);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &0), None);
+		})
+	}
+
+	#[test]
+	fn burn_checks_owner() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_noop!(
+				<NFTs as Mutate<u64>>::burn(&0, &0, Some(&BOB)),
+				Error::<TestRuntime>::NotOwned
+			);
+		})
+	}
+
+	#[test]
+	fn burn_must_have_sole_owner() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+			assert_noop!(
+				<NFTs as Mutate<u64>>::burn(&0, &0, None),
+				Error::<TestRuntime>::NoSoleOwner
+			);
+		})
+	}
+
+	#[test]
+	fn set_attribute() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(<NFTs as Mutate<u64>>::set_attribute(
+				&0,
+				&0,
+				"color".as_bytes(),
+				"blue".as_bytes()
+			));
+
+			assert_eq!(
+				<NFTs as Inspect<u64>>::attribute(&0, &0, "color".as_bytes()),
+				Some("blue".as_bytes().to_vec())
+			);
+		})
+	}
+
+	#[test]
+	fn set_attribute_must_fit() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_noop!(
+				<NFTs as Mutate<u64>>::set_attribute(
+					&0,
+					&0,
+					"a key longer than the limit".as_bytes(),
+					"blue".as_bytes()
+				),
+				Error::<TestRuntime>::AttributeTooLong
+			);
+		})
+	}
+}
+
+mod transfer {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(<NFTs as Transfer<u64>>::transfer(&0, &0, &BOB));
+
+			assert_eq!(NFTs::account(0, ALICE), 0);
+			assert_eq!(NFTs::account(0, BOB), 5);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&0, &0), Some(BOB));
+		})
+	}
+
+	#[test]
+	fn must_have_sole_owner() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+			assert_noop!(
+				<NFTs as Transfer<u64>>::transfer(&0, &0, &CHARLIE),
+				Error::<TestRuntime>::NoSoleOwner
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_locked() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_ok!(NFTs::lock(Origin::signed(ALICE), 0));
+			assert_noop!(
+				<NFTs as Transfer<u64>>::transfer(&0, &0, &BOB),
+				Error::<TestRuntime>::Locked
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_in_collection() {
+		new_test_ext().execute_with(|| {
+			create_collection_and_mint(5);
+			assert_noop!(
+				<NFTs as Transfer<u64>>::transfer(&1, &0, &BOB),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+}
@@ -1,5 +1,11 @@
-use crate::{tests::mock::*, Error};
-use frame_support::{assert_noop, assert_ok, error::BadOrigin, BoundedVec};
+use crate::{
+	tests::mock::*,
+	types::{MintVoucher, Royalty},
+	Error,
+};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, sp_runtime::Perbill, BoundedVec};
+use sp_runtime::testing::TestSignature;
 
 fn last_event() -> Event {
 	frame_system::Pallet::<TestRuntime>::events()
@@ -8,6 +14,66 @@ fn last_event() -> Event {
 		.event
 }
 
+fn create_default_collection(owner: u64) {
+	assert_ok!(NFTs::create_collection(
+		Origin::signed(owner),
+		None,
+		"Some collection".as_bytes().to_vec().try_into().unwrap()
+	));
+}
+
+// The balances of all holders of `asset_id` add up to its supply.
+fn assert_supply_invariant(asset_id: u128) {
+	let held: u128 = NFTs::holders_of(asset_id).iter().map(|(_, balance)| balance).sum();
+	assert_eq!(held, NFTs::unique_asset(asset_id).unwrap().supply);
+}
+
+mod create_collection {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			let metadata: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
+				"Some collection".as_bytes().to_vec().try_into().unwrap();
+			assert_ok!(NFTs::create_collection(
+				Origin::signed(ALICE),
+				Some(2),
+				metadata.clone()
+			));
+
+			assert_eq!(NFTs::collection_nonce(), 1);
+
+			let collection = NFTs::collection(0).unwrap();
+			assert_eq!(collection.owner(), ALICE);
+			assert_eq!(collection.metadata(), metadata);
+			assert_eq!(collection.max_items, Some(2));
+			assert_eq!(collection.items, 0);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::CollectionCreated {
+					owner: ALICE,
+					collection_id: 0
+				})
+			)
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::create_collection(
+					Origin::none(),
+					None,
+					"Some collection".as_bytes().to_vec().try_into().unwrap()
+				),
+				BadOrigin
+			);
+		})
+	}
+}
+
 mod mint {
 	use super::*;
 
@@ -16,19 +82,31 @@ mod mint {
 		new_test_ext().execute_with(|| {
 			let metadata: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
 				"Some metadata".as_bytes().to_vec().try_into().unwrap();
-			assert_ok!(NFTs::mint(Origin::signed(ALICE), metadata.clone(), 5));
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				metadata.clone(),
+				5,
+				None,
+				true
+			));
 
 			assert_eq!(NFTs::nonce(), 1);
 
 			let details = NFTs::unique_asset(0).unwrap();
+			assert_eq!(details.collection_id(), 0);
 			assert_eq!(details.creator(), ALICE);
 			assert_eq!(details.metadata(), metadata);
 			assert_eq!(details.supply, 5);
 			assert_eq!(NFTs::account(0, ALICE), 5);
+			assert_eq!(NFTs::collection(0).unwrap().items, 1);
+			assert_eq!(NFTs::collection_assets(0, 0), Some(()));
 			assert_eq!(
 				last_event(),
 				Event::NFTs(crate::Event::Created {
 					creator: ALICE,
+					collection_id: 0,
 					asset_id: 0
 				})
 			)
@@ -41,8 +119,11 @@ mod mint {
 			assert_noop!(
 				NFTs::mint(
 					Origin::none(),
+					0,
 					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-					5
+					5,
+					None,
+					true
 				),
 				BadOrigin
 			);
@@ -55,13 +136,124 @@ mod mint {
 			assert_noop!(
 				NFTs::mint(
 					Origin::signed(ALICE),
+					0,
 					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-					0
+					0,
+					None,
+					true
 				),
 				Error::<TestRuntime>::NoSupply
 			);
 		})
 	}
+
+	#[test]
+	fn must_have_collection() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::mint(
+					Origin::signed(ALICE),
+					0,
+					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+					5,
+					None,
+					true
+				),
+				Error::<TestRuntime>::UnknownCollection
+			);
+		})
+	}
+
+	#[test]
+	fn must_own_collection() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_noop!(
+				NFTs::mint(
+					Origin::signed(BOB),
+					0,
+					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+					5,
+					None,
+					true
+				),
+				Error::<TestRuntime>::NotCollectionOwner
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_exceed_max_items() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(NFTs::create_collection(
+				Origin::signed(ALICE),
+				Some(1),
+				"Some collection".as_bytes().to_vec().try_into().unwrap()
+			));
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::mint(
+					Origin::signed(ALICE),
+					0,
+					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+					5,
+					None,
+					true
+				),
+				Error::<TestRuntime>::CollectionFull
+			);
+		})
+	}
+
+	#[test]
+	fn ok_with_royalty() {
+		new_test_ext().execute_with(|| {
+			let royalty = Royalty {
+				beneficiary: BOB,
+				rate: Perbill::from_percent(10),
+			};
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				Some(royalty.clone()),
+				true
+			));
+
+			assert_eq!(NFTs::unique_asset(0).unwrap().royalty(), Some(royalty));
+			assert_eq!(NFTs::royalty_info(0, 1_000), Some((BOB, 100)));
+		})
+	}
+
+	#[test]
+	fn must_not_exceed_max_royalty() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_noop!(
+				NFTs::mint(
+					Origin::signed(ALICE),
+					0,
+					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+					5,
+					Some(Royalty {
+						beneficiary: BOB,
+						rate: Perbill::from_percent(21),
+					}),
+					true
+				),
+				Error::<TestRuntime>::RoyaltyTooHigh
+			);
+		})
+	}
 }
 
 mod transfer {
@@ -72,10 +264,14 @@ mod transfer {
 		new_test_ext().execute_with(|| {
 			let minted_amount = 5;
 			let transfered_amount = 2;
+			create_default_collection(ALICE);
 			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
+				0,
 				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-				minted_amount
+				minted_amount,
+				None,
+				true
 			));
 			assert_ok!(NFTs::transfer(
 				Origin::signed(ALICE),
@@ -100,34 +296,40 @@ mod transfer {
 	}
 
 	#[test]
-	fn ok_saturating() {
+	fn must_have_enough_balance() {
 		new_test_ext().execute_with(|| {
-			let minted_amount = 5;
-			let transfered_amount = 10;
+			create_default_collection(ALICE);
 			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
+				0,
 				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-				minted_amount
+				5,
+				None,
+				true
 			));
-			assert_ok!(NFTs::transfer(
+			assert_noop!(
+				NFTs::transfer(Origin::signed(ALICE), 0, 10, BOB),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_positive() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
 				0,
-				transfered_amount,
-				BOB
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
 			));
-
-			assert_eq!(NFTs::account(0, ALICE), 0);
-			assert_eq!(NFTs::account(0, BOB), minted_amount);
-
-			assert_eq!(
-				last_event(),
-				Event::NFTs(crate::Event::Transferred {
-					asset_id: 0,
-					from: ALICE,
-					to: BOB,
-					amount: minted_amount
-				})
-			)
+			assert_noop!(
+				NFTs::transfer(Origin::signed(ALICE), 0, 0, BOB),
+				Error::<TestRuntime>::ZeroAmount
+			);
 		})
 	}
 
@@ -137,8 +339,11 @@ mod transfer {
 			assert_noop!(
 				NFTs::mint(
 					Origin::none(),
+					0,
 					"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-					5
+					5,
+					None,
+					true
 				),
 				BadOrigin
 			);
@@ -158,10 +363,14 @@ mod transfer {
 	#[test]
 	fn must_own_some() {
 		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
 			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
+				0,
 				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-				5
+				5,
+				None,
+				true
 			));
 			assert_noop!(
 				NFTs::transfer(Origin::signed(BOB), 0, 2, ALICE),
@@ -179,10 +388,14 @@ mod burn {
 		new_test_ext().execute_with(|| {
 			let minted_amount = 5;
 			let burned_amount = 2;
+			create_default_collection(ALICE);
 			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
+				0,
 				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-				minted_amount
+				minted_amount,
+				None,
+				true
 			));
 			assert_ok!(NFTs::burn(Origin::signed(ALICE), 0, burned_amount));
 
@@ -204,27 +417,105 @@ mod burn {
 	}
 
 	#[test]
-	fn ok_saturating() {
+	fn must_have_enough_balance() {
 		new_test_ext().execute_with(|| {
-			let minted_amount = 5;
-			let burned_amount = 10;
+			create_default_collection(ALICE);
 			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
+				0,
 				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-				minted_amount
+				5,
+				None,
+				true
 			));
+			assert_noop!(
+				NFTs::burn(Origin::signed(ALICE), 0, 10),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
 
-			assert_ok!(NFTs::burn(Origin::signed(ALICE), 0, burned_amount));
+	#[test]
+	fn must_be_positive() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::burn(Origin::signed(ALICE), 0, 0),
+				Error::<TestRuntime>::ZeroAmount
+			);
+		})
+	}
 
-			assert_eq!(NFTs::unique_asset(0).unwrap().supply, 0);
-			assert_eq!(NFTs::account(0, ALICE), 0);
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(NFTs::burn(Origin::none(), 0, 5), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::burn(Origin::signed(ALICE), 0, 100),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_own_some() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::burn(Origin::signed(BOB), 0, 2),
+				Error::<TestRuntime>::NotOwned
+			);
+		})
+	}
+}
+
+mod approve {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::approve(Origin::signed(ALICE), 0, BOB, 3));
 
+			assert_eq!(NFTs::approvals((0, ALICE, BOB)), Some(3));
 			assert_eq!(
 				last_event(),
-				Event::NFTs(crate::Event::Burned {
+				Event::NFTs(crate::Event::Approved {
 					asset_id: 0,
 					owner: ALICE,
-					total_supply: 0
+					operator: BOB,
+					amount: 3
 				})
 			)
 		})
@@ -233,7 +524,7 @@ mod burn {
 	#[test]
 	fn must_be_signed() {
 		new_test_ext().execute_with(|| {
-			assert_noop!(NFTs::burn(Origin::none(), 0, 5), BadOrigin);
+			assert_noop!(NFTs::approve(Origin::none(), 0, BOB, 3), BadOrigin);
 		})
 	}
 
@@ -241,23 +532,1265 @@ mod burn {
 	fn must_exist() {
 		new_test_ext().execute_with(|| {
 			assert_noop!(
-				NFTs::burn(Origin::signed(ALICE), 0, 100),
+				NFTs::approve(Origin::signed(ALICE), 0, BOB, 3),
 				Error::<TestRuntime>::UnknownAssetId
 			);
 		})
 	}
+}
+
+mod transfer_from {
+	use super::*;
 
 	#[test]
-	fn must_own_some() {
+	fn ok() {
 		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
 			assert_ok!(NFTs::mint(
 				Origin::signed(ALICE),
+				0,
 				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
-				5
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::approve(Origin::signed(ALICE), 0, BOB, 3));
+			assert_ok!(NFTs::transfer_from(
+				Origin::signed(BOB),
+				0,
+				ALICE,
+				CHARLIE,
+				2
+			));
+
+			assert_eq!(NFTs::account(0, ALICE), 3);
+			assert_eq!(NFTs::account(0, CHARLIE), 2);
+			assert_eq!(NFTs::approvals((0, ALICE, BOB)), Some(1));
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::Transferred {
+					asset_id: 0,
+					from: ALICE,
+					to: CHARLIE,
+					amount: 2
+				})
+			);
+
+			assert_ok!(NFTs::transfer_from(
+				Origin::signed(BOB),
+				0,
+				ALICE,
+				CHARLIE,
+				1
 			));
+			assert_eq!(NFTs::approvals((0, ALICE, BOB)), None);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
 			assert_noop!(
-				NFTs::burn(Origin::signed(BOB), 0, 2),
-				Error::<TestRuntime>::NotOwned
+				NFTs::transfer_from(Origin::none(), 0, ALICE, CHARLIE, 2),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_approved() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::transfer_from(Origin::signed(BOB), 0, ALICE, CHARLIE, 2),
+				Error::<TestRuntime>::Unapproved
+			);
+
+			assert_ok!(NFTs::approve(Origin::signed(ALICE), 0, BOB, 1));
+			assert_noop!(
+				NFTs::transfer_from(Origin::signed(BOB), 0, ALICE, CHARLIE, 2),
+				Error::<TestRuntime>::Unapproved
+			);
+		})
+	}
+
+	#[test]
+	fn must_have_enough_balance() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::approve(Origin::signed(ALICE), 0, BOB, 10));
+			assert_noop!(
+				NFTs::transfer_from(Origin::signed(BOB), 0, ALICE, CHARLIE, 6),
+				Error::<TestRuntime>::InsufficientBalance
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_positive() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::approve(Origin::signed(ALICE), 0, BOB, 10));
+			assert_noop!(
+				NFTs::transfer_from(Origin::signed(BOB), 0, ALICE, CHARLIE, 0),
+				Error::<TestRuntime>::ZeroAmount
+			);
+		})
+	}
+}
+
+mod set_attribute {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			let key: BoundedVec<u8, KeyLimit> = "color".as_bytes().to_vec().try_into().unwrap();
+			let value: BoundedVec<u8, ValueLimit> = "red".as_bytes().to_vec().try_into().unwrap();
+			assert_ok!(NFTs::set_attribute(
+				Origin::signed(ALICE),
+				0,
+				key.clone(),
+				value.clone()
+			));
+
+			assert_eq!(NFTs::attribute(0, key.clone()), Some(value.clone()));
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::AttributeSet {
+					asset_id: 0,
+					key,
+					value
+				})
+			)
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::set_attribute(
+					Origin::none(),
+					0,
+					"color".as_bytes().to_vec().try_into().unwrap(),
+					"red".as_bytes().to_vec().try_into().unwrap()
+				),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::set_attribute(
+					Origin::signed(ALICE),
+					0,
+					"color".as_bytes().to_vec().try_into().unwrap(),
+					"red".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::set_attribute(
+					Origin::signed(BOB),
+					0,
+					"color".as_bytes().to_vec().try_into().unwrap(),
+					"red".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::NotCreator
+			);
+		})
+	}
+}
+
+mod clear_attribute {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			let key: BoundedVec<u8, KeyLimit> = "color".as_bytes().to_vec().try_into().unwrap();
+			assert_ok!(NFTs::set_attribute(
+				Origin::signed(ALICE),
+				0,
+				key.clone(),
+				"red".as_bytes().to_vec().try_into().unwrap()
+			));
+			assert_ok!(NFTs::clear_attribute(Origin::signed(ALICE), 0, key.clone()));
+
+			assert_eq!(NFTs::attribute(0, key.clone()), None);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::AttributeCleared { asset_id: 0, key })
+			)
+		})
+	}
+
+	#[test]
+	fn must_be_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::set_attribute(
+				Origin::signed(ALICE),
+				0,
+				"color".as_bytes().to_vec().try_into().unwrap(),
+				"red".as_bytes().to_vec().try_into().unwrap()
+			));
+			assert_noop!(
+				NFTs::clear_attribute(
+					Origin::signed(BOB),
+					0,
+					"color".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::NotCreator
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_set() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::clear_attribute(
+					Origin::signed(ALICE),
+					0,
+					"color".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::UnknownAttribute
+			);
+		})
+	}
+}
+
+mod royalty_info {
+	use super::*;
+
+	#[test]
+	fn none_without_royalty() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(NFTs::royalty_info(0, 1_000), None);
+
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_eq!(NFTs::royalty_info(0, 1_000), None);
+		})
+	}
+}
+
+mod assets_of {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			let mut assets = NFTs::assets_of(ALICE);
+			assets.sort();
+			assert_eq!(assets, vec![0, 1]);
+			assert!(NFTs::assets_of(BOB).is_empty());
+		})
+	}
+
+	#[test]
+	fn follows_transfers() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+			assert_eq!(NFTs::assets_of(ALICE), vec![0]);
+			assert_eq!(NFTs::assets_of(BOB), vec![0]);
+
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 3, BOB));
+			assert!(NFTs::assets_of(ALICE).is_empty());
+			assert_eq!(NFTs::assets_of(BOB), vec![0]);
+		})
+	}
+
+	#[test]
+	fn dropped_on_burn() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			assert_ok!(NFTs::burn(Origin::signed(ALICE), 0, 5));
+			assert!(NFTs::assets_of(ALICE).is_empty());
+		})
+	}
+
+	#[test]
+	fn not_indexed_on_zero_transfer() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			// Whether or not it is accepted, a zero transfer credits nothing.
+			let _ = NFTs::transfer(Origin::signed(ALICE), 0, 0, BOB);
+			assert!(NFTs::assets_of(BOB).is_empty());
+			assert!(!crate::Account::<TestRuntime>::contains_key(0, BOB));
+		})
+	}
+}
+
+mod holders_of {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+
+			let mut holders = NFTs::holders_of(0);
+			holders.sort();
+			assert_eq!(holders, vec![(ALICE, 3), (BOB, 2)]);
+			assert!(NFTs::holders_of(1).is_empty());
+		})
+	}
+}
+
+mod lock {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::lock(Origin::signed(ALICE), 0));
+
+			assert!(!NFTs::unique_asset(0).unwrap().transferable);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::Locked { asset_id: 0 })
+			);
+			assert_noop!(
+				NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB),
+				Error::<TestRuntime>::Locked
+			);
+			assert_ok!(NFTs::burn(Origin::signed(ALICE), 0, 2));
+		})
+	}
+
+	#[test]
+	fn locked_at_mint() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				false
+			));
+			assert_ok!(NFTs::approve(Origin::signed(ALICE), 0, BOB, 2));
+
+			assert_noop!(
+				NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB),
+				Error::<TestRuntime>::Locked
+			);
+			assert_noop!(
+				NFTs::transfer_from(Origin::signed(BOB), 0, ALICE, CHARLIE, 2),
+				Error::<TestRuntime>::Locked
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(NFTs::lock(Origin::none(), 0), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::lock(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::lock(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NotCreator
+			);
+		})
+	}
+}
+
+mod unlock {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				false
+			));
+			assert_ok!(NFTs::unlock(Origin::signed(ALICE), 0));
+
+			assert!(NFTs::unique_asset(0).unwrap().transferable);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::Unlocked { asset_id: 0 })
+			);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+		})
+	}
+
+	#[test]
+	fn must_be_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				false
+			));
+			assert_noop!(
+				NFTs::unlock(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NotCreator
+			);
+		})
+	}
+}
+
+mod update_metadata {
+	use super::*;
+	use frame_support::sp_runtime::traits::{BlakeTwo256, Hash};
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			let metadata: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::MaxLength> =
+				"New metadata".as_bytes().to_vec().try_into().unwrap();
+			assert_ok!(NFTs::update_metadata(
+				Origin::signed(ALICE),
+				0,
+				metadata.clone()
+			));
+
+			assert_eq!(NFTs::unique_asset(0).unwrap().metadata(), metadata);
+			assert_eq!(
+				NFTs::metadata_history(0).into_inner(),
+				vec![(1, BlakeTwo256::hash("Some metadata".as_bytes()))]
+			);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::MetadataUpdated {
+					asset_id: 0,
+					metadata
+				})
+			)
+		})
+	}
+
+	#[test]
+	fn history_is_bounded() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"v0".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			for (block, metadata) in [(1, "v1"), (2, "v2"), (3, "v3")] {
+				System::set_block_number(block);
+				assert_ok!(NFTs::update_metadata(
+					Origin::signed(ALICE),
+					0,
+					metadata.as_bytes().to_vec().try_into().unwrap()
+				));
+			}
+
+			// Only the last `MaxMetadataHistory` entries are kept, oldest first.
+			assert_eq!(
+				NFTs::metadata_history(0).into_inner(),
+				vec![
+					(2, BlakeTwo256::hash("v1".as_bytes())),
+					(3, BlakeTwo256::hash("v2".as_bytes()))
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::update_metadata(
+					Origin::none(),
+					0,
+					"New metadata".as_bytes().to_vec().try_into().unwrap()
+				),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::update_metadata(
+					Origin::signed(ALICE),
+					0,
+					"New metadata".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::update_metadata(
+					Origin::signed(BOB),
+					0,
+					"New metadata".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::NotCreator
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_frozen() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::freeze_metadata(Origin::signed(ALICE), 0));
+			assert_noop!(
+				NFTs::update_metadata(
+					Origin::signed(ALICE),
+					0,
+					"New metadata".as_bytes().to_vec().try_into().unwrap()
+				),
+				Error::<TestRuntime>::MetadataFrozen
+			);
+		})
+	}
+}
+
+mod freeze_metadata {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::freeze_metadata(Origin::signed(ALICE), 0));
+
+			assert!(NFTs::unique_asset(0).unwrap().metadata_frozen);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::MetadataFrozen { asset_id: 0 })
+			)
+		})
+	}
+
+	#[test]
+	fn must_be_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::freeze_metadata(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NotCreator
+			);
+		})
+	}
+}
+
+mod redeem {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let royalty = Royalty {
+				beneficiary: ALICE,
+				rate: Perbill::from_percent(10),
+			};
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				Some(royalty.clone()),
+				true
+			));
+			let key: BoundedVec<u8, <TestRuntime as crate::pallet::Config>::KeyLimit> =
+				"color".as_bytes().to_vec().try_into().unwrap();
+			assert_ok!(NFTs::set_attribute(
+				Origin::signed(ALICE),
+				0,
+				key.clone(),
+				"blue".as_bytes().to_vec().try_into().unwrap()
+			));
+			assert_ok!(NFTs::update_metadata(
+				Origin::signed(ALICE),
+				0,
+				"New metadata".as_bytes().to_vec().try_into().unwrap()
+			));
+			let history = NFTs::metadata_history(0);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 5, BOB));
+			assert_ok!(NFTs::approve(Origin::signed(BOB), 0, CHARLIE, 5));
+
+			assert_ok!(NFTs::redeem(Origin::signed(BOB), 0));
+
+			// The fractions are gone, along with everything stored under their ID.
+			assert!(NFTs::unique_asset(0).is_none());
+			assert_eq!(NFTs::collection_assets(0, 0), None);
+			assert_eq!(NFTs::account(0, BOB), 0);
+			assert_eq!(NFTs::approvals((0, BOB, CHARLIE)), None);
+			assert_eq!(NFTs::attribute(0, key.clone()), None);
+			assert!(NFTs::metadata_history(0).is_empty());
+
+			// They are replaced by a single-supply asset.
+			let details = NFTs::unique_asset(1).unwrap();
+			assert_eq!(details.supply, 1);
+			assert_eq!(details.collection_id(), 0);
+			assert_eq!(details.creator(), ALICE);
+			assert_eq!(details.metadata(), "New metadata".as_bytes().to_vec());
+			assert_eq!(details.royalty(), Some(royalty));
+			assert_eq!(NFTs::collection_assets(0, 1), Some(()));
+			assert_eq!(NFTs::account(1, BOB), 1);
+			assert_eq!(NFTs::assets_of(BOB), vec![1]);
+			assert_eq!(
+				NFTs::attribute(1, key),
+				Some("blue".as_bytes().to_vec().try_into().unwrap())
+			);
+			assert_eq!(NFTs::metadata_history(1), history);
+			assert_supply_invariant(1);
+
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::Redeemed {
+					asset_id: 0,
+					new_asset_id: 1,
+					owner: BOB
+				})
+			)
+		})
+	}
+
+	#[test]
+	fn ok_in_full_collection() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(NFTs::create_collection(
+				Origin::signed(ALICE),
+				Some(1),
+				"Some collection".as_bytes().to_vec().try_into().unwrap()
+			));
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+
+			assert_ok!(NFTs::redeem(Origin::signed(ALICE), 0));
+
+			assert_eq!(NFTs::collection(0).unwrap().items, 1);
+			assert_eq!(
+				crate::CollectionAssets::<TestRuntime>::iter_key_prefix(0).collect::<Vec<_>>(),
+				vec![1]
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(NFTs::redeem(Origin::none(), 0), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::redeem(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_own_some() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::redeem(Origin::signed(BOB), 0),
+				Error::<TestRuntime>::NotOwned
+			);
+		})
+	}
+
+	#[test]
+	fn must_hold_whole_supply() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 1, BOB));
+			assert_noop!(
+				NFTs::redeem(Origin::signed(ALICE), 0),
+				Error::<TestRuntime>::NoSoleOwner
+			);
+		})
+	}
+}
+
+mod fractionalize {
+	use super::*;
+
+	#[test]
+	fn ok_split() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				1,
+				None,
+				true
+			));
+
+			assert_ok!(NFTs::fractionalize(Origin::signed(ALICE), 0, 100));
+
+			assert_eq!(NFTs::unique_asset(0).unwrap().supply, 100);
+			assert_eq!(NFTs::account(0, ALICE), 100);
+			assert_supply_invariant(0);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::Fractionalized {
+					asset_id: 0,
+					owner: ALICE,
+					supply: 100
+				})
+			)
+		})
+	}
+
+	#[test]
+	fn ok_merge() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+			assert_ok!(NFTs::transfer(Origin::signed(BOB), 0, 2, ALICE));
+
+			assert_ok!(NFTs::fractionalize(Origin::signed(ALICE), 0, 1));
+
+			assert_eq!(NFTs::unique_asset(0).unwrap().supply, 1);
+			assert_eq!(NFTs::account(0, ALICE), 1);
+			assert_eq!(NFTs::holders_of(0), vec![(ALICE, 1)]);
+			assert_supply_invariant(0);
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(NFTs::fractionalize(Origin::none(), 0, 10), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn must_have_positive_supply() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_noop!(
+				NFTs::fractionalize(Origin::signed(ALICE), 0, 0),
+				Error::<TestRuntime>::NoSupply
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				NFTs::fractionalize(Origin::signed(ALICE), 0, 10),
+				Error::<TestRuntime>::UnknownAssetId
+			);
+		})
+	}
+
+	#[test]
+	fn must_hold_whole_supply() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			assert_ok!(NFTs::mint(
+				Origin::signed(ALICE),
+				0,
+				"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+				5,
+				None,
+				true
+			));
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 1, BOB));
+			assert_noop!(
+				NFTs::fractionalize(Origin::signed(ALICE), 0, 10),
+				Error::<TestRuntime>::NoSoleOwner
+			);
+			assert_noop!(
+				NFTs::fractionalize(Origin::signed(CHARLIE), 0, 10),
+				Error::<TestRuntime>::NotOwned
+			);
+			assert_supply_invariant(0);
+		})
+	}
+}
+
+mod mint_with_voucher {
+	use super::*;
+
+	fn default_voucher(creator: u64, nonce: u64) -> MintVoucher<TestRuntime> {
+		MintVoucher {
+			creator,
+			collection_id: 0,
+			metadata: "Some metadata".as_bytes().to_vec().try_into().unwrap(),
+			supply: 5,
+			beneficiary: None,
+			nonce,
+			expiry: 10,
+		}
+	}
+
+	fn sign(signer: u64, voucher: &MintVoucher<TestRuntime>) -> TestSignature {
+		TestSignature(
+			signer,
+			(b"pallet-nft/voucher", System::block_hash(0), voucher).encode(),
+		)
+	}
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(ALICE, 0);
+			let signature = sign(ALICE, &voucher);
+
+			assert_ok!(NFTs::mint_with_voucher(
+				Origin::signed(BOB),
+				voucher,
+				signature
+			));
+
+			let details = NFTs::unique_asset(0).unwrap();
+			assert_eq!(details.creator(), ALICE);
+			assert_eq!(details.metadata(), "Some metadata".as_bytes().to_vec());
+			assert_eq!(details.supply, 5);
+			assert_eq!(NFTs::account(0, BOB), 5);
+			assert_eq!(NFTs::account(0, ALICE), 0);
+			assert_eq!(
+				last_event(),
+				Event::NFTs(crate::Event::Created {
+					creator: ALICE,
+					collection_id: 0,
+					asset_id: 0
+				})
+			)
+		})
+	}
+
+	#[test]
+	fn must_be_signed() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(ALICE, 0);
+			let signature = sign(ALICE, &voucher);
+
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::none(), voucher, signature),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn must_be_used_by_beneficiary() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let mut voucher = default_voucher(ALICE, 0);
+			voucher.beneficiary = Some(BOB);
+			let signature = sign(ALICE, &voucher);
+
+			// Someone copying the voucher from the transaction pool cannot take the asset.
+			assert_noop!(
+				NFTs::mint_with_voucher(
+					Origin::signed(CHARLIE),
+					voucher.clone(),
+					signature.clone()
+				),
+				Error::<TestRuntime>::NotBeneficiary
+			);
+
+			assert_ok!(NFTs::mint_with_voucher(
+				Origin::signed(BOB),
+				voucher,
+				signature
+			));
+			assert_eq!(NFTs::account(0, BOB), 5);
+		})
+	}
+
+	#[test]
+	fn must_be_signed_by_creator() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(ALICE, 0);
+			let signature = sign(BOB, &voucher);
+
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(BOB), voucher, signature),
+				Error::<TestRuntime>::InvalidSignature
+			);
+		})
+	}
+
+	#[test]
+	fn must_sign_domain_separated_payload() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(ALICE, 0);
+			let signature = TestSignature(ALICE, voucher.encode());
+
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(BOB), voucher, signature),
+				Error::<TestRuntime>::InvalidSignature
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_tampered() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let mut voucher = default_voucher(ALICE, 0);
+			let signature = sign(ALICE, &voucher);
+			voucher.supply = 500;
+
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(BOB), voucher, signature),
+				Error::<TestRuntime>::InvalidSignature
+			);
+		})
+	}
+
+	#[test]
+	fn must_not_be_expired() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(ALICE, 0);
+			let signature = sign(ALICE, &voucher);
+
+			System::set_block_number(11);
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(BOB), voucher, signature),
+				Error::<TestRuntime>::VoucherExpired
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_be_replayed() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(ALICE, 0);
+			let signature = sign(ALICE, &voucher);
+
+			assert_ok!(NFTs::mint_with_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				signature.clone()
+			));
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(CHARLIE), voucher, signature),
+				Error::<TestRuntime>::VoucherUsed
+			);
+
+			// Another nonce can still be used.
+			let voucher = default_voucher(ALICE, 1);
+			let signature = sign(ALICE, &voucher);
+			assert_ok!(NFTs::mint_with_voucher(
+				Origin::signed(CHARLIE),
+				voucher,
+				signature
+			));
+		})
+	}
+
+	#[test]
+	fn creator_must_own_collection() {
+		new_test_ext().execute_with(|| {
+			create_default_collection(ALICE);
+			let voucher = default_voucher(BOB, 0);
+			let signature = sign(BOB, &voucher);
+
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(CHARLIE), voucher, signature),
+				Error::<TestRuntime>::NotCollectionOwner
+			);
+		})
+	}
+
+	#[test]
+	fn collection_must_exist() {
+		new_test_ext().execute_with(|| {
+			let voucher = default_voucher(ALICE, 0);
+			let signature = sign(ALICE, &voucher);
+
+			assert_noop!(
+				NFTs::mint_with_voucher(Origin::signed(BOB), voucher, signature),
+				Error::<TestRuntime>::UnknownCollection
 			);
 		})
 	}
@@ -2,31 +2,92 @@ use crate::Config;
 use codec::{Decode, Encode};
 use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
+	sp_runtime::Perbill,
 	traits::Get,
-	RuntimeDebug,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
 
 pub type UniqueAssetId = u128;
+pub type CollectionId = u128;
+
+#[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(S))]
+#[codec(mel_bound())]
+pub struct CollectionDetails<T: Config, S: Get<u32>> {
+	owner: T::AccountId,
+	metadata: BoundedVec<u8, S>,
+	/// The maximum number of assets that can be minted in the collection, if any.
+	pub max_items: Option<u32>,
+	/// The number of assets minted in the collection.
+	pub items: u32,
+}
+
+impl<T: Config, S: Get<u32>> CollectionDetails<T, S> {
+	pub fn new(owner: T::AccountId, metadata: BoundedVec<u8, S>, max_items: Option<u32>) -> Self {
+		CollectionDetails {
+			owner,
+			metadata,
+			max_items,
+			items: 0,
+		}
+	}
+
+	pub fn owner(&self) -> T::AccountId {
+		self.owner.clone()
+	}
+
+	pub fn metadata(&self) -> BoundedVec<u8, S> {
+		self.metadata.clone()
+	}
+}
+
+/// The share of every sale of an asset paid to `beneficiary`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Royalty<AccountId> {
+	pub beneficiary: AccountId,
+	pub rate: Perbill,
+}
 
 #[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(S))]
 #[codec(mel_bound())]
 pub struct UniqueAssetDetails<T: Config, S: Get<u32>> {
+	collection_id: CollectionId,
 	creator: T::AccountId,
 	metadata: BoundedVec<u8, S>,
+	royalty: Option<Royalty<T::AccountId>>,
 	pub supply: u128,
+	/// Whether the asset can be transferred. Locked assets can still be burned.
+	pub transferable: bool,
+	/// Whether the creator can no longer update the metadata.
+	pub metadata_frozen: bool,
 }
 
 impl<T: Config, S: Get<u32>> UniqueAssetDetails<T, S> {
-	pub fn new(creator: T::AccountId, metadata: BoundedVec<u8, S>, supply: u128) -> Self {
+	pub fn new(
+		collection_id: CollectionId,
+		creator: T::AccountId,
+		metadata: BoundedVec<u8, S>,
+		royalty: Option<Royalty<T::AccountId>>,
+		supply: u128,
+		transferable: bool,
+	) -> Self {
 		UniqueAssetDetails {
+			collection_id,
 			creator,
 			metadata,
+			royalty,
 			supply,
+			transferable,
+			metadata_frozen: false,
 		}
 	}
 
+	pub fn collection_id(&self) -> CollectionId {
+		self.collection_id
+	}
+
 	pub fn creator(&self) -> T::AccountId {
 		self.creator.clone()
 	}
@@ -34,4 +95,32 @@ impl<T: Config, S: Get<u32>> UniqueAssetDetails<T, S> {
 	pub fn metadata(&self) -> BoundedVec<u8, S> {
 		self.metadata.clone()
 	}
+
+	/// Replace the metadata, returning the previous one.
+	pub fn set_metadata(&mut self, metadata: BoundedVec<u8, S>) -> BoundedVec<u8, S> {
+		sp_std::mem::replace(&mut self.metadata, metadata)
+	}
+
+	pub fn royalty(&self) -> Option<Royalty<T::AccountId>> {
+		self.royalty.clone()
+	}
+}
+
+/// An authorisation from `creator` to mint an asset in `collection_id`, signed off-chain so that
+/// anyone can pay for the mint with `mint_with_voucher`.
+#[derive(
+	Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct MintVoucher<T: Config> {
+	pub creator: T::AccountId,
+	pub collection_id: CollectionId,
+	pub metadata: BoundedVec<u8, T::MaxLength>,
+	pub supply: u128,
+	/// The only account allowed to use the voucher, if any.
+	pub beneficiary: Option<T::AccountId>,
+	/// Can only be used once by each creator, so that the voucher cannot be replayed.
+	pub nonce: u64,
+	/// The last block at which the voucher can be used.
+	pub expiry: T::BlockNumber,
 }
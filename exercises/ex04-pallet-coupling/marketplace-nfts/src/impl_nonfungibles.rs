@@ -0,0 +1,68 @@
+//! This pallet has no collections: every asset is an item of the single collection `()`, which
+//! has no owner and cannot be created. The owner of an item is the account whose balance is the
+//! whole supply of the asset; burning or transferring an item moves that balance. There is no
+//! locking either, so any item with an owner can be transferred. No attributes are stored.
+
+use super::*;
+use frame_support::traits::tokens::nonfungibles;
+use sp_runtime::TokenError;
+
+impl<T: Config> nonfungibles::Inspect<T::AccountId> for Pallet<T> {
+	type CollectionId = ();
+	type ItemId = T::NFTId;
+
+	fn owner(_collection: &(), item: &T::NFTId) -> Option<T::AccountId> {
+		Self::sole_owner(*item)
+	}
+
+	fn can_transfer(collection: &(), item: &T::NFTId) -> bool {
+		Self::owner(collection, item).is_some()
+	}
+}
+
+impl<T: Config> nonfungibles::Create<T::AccountId> for Pallet<T> {
+	/// The only collection always exists, so no collection can be created.
+	fn create_collection(
+		_collection: &(),
+		_who: &T::AccountId,
+		_admin: &T::AccountId,
+	) -> DispatchResult {
+		Err(TokenError::Unsupported.into())
+	}
+}
+
+impl<T: Config> nonfungibles::Mutate<T::AccountId> for Pallet<T> {
+	/// Mint `item` with a supply of 1 and no metadata, created and owned by `who`.
+	///
+	/// `item` must be `Nonce`, the ID the next minted asset gets.
+	fn mint_into(_collection: &(), item: &T::NFTId, who: &T::AccountId) -> DispatchResult {
+		ensure!(*item == Self::nonce(), Error::<T>::UnavailableId);
+
+		Self::do_mint(who.clone(), Default::default(), 1);
+		Ok(())
+	}
+
+	fn burn(
+		_collection: &(),
+		item: &T::NFTId,
+		maybe_check_owner: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let details = Self::unique_asset(item).ok_or(Error::<T>::Unknown)?;
+		let owner = Self::sole_owner(*item).ok_or(Error::<T>::NoSoleOwner)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(*check_owner == owner, Error::<T>::NotOwned);
+		}
+
+		Self::do_burn(*item, owner, details.supply)
+	}
+}
+
+impl<T: Config> nonfungibles::Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(_collection: &(), item: &T::NFTId, destination: &T::AccountId) -> DispatchResult {
+		let details = Self::unique_asset(item).ok_or(Error::<T>::Unknown)?;
+		let owner = Self::sole_owner(*item).ok_or(Error::<T>::NoSoleOwner)?;
+
+		Self::unchecked_transfer(*item, owner, destination.clone(), details.supply);
+		Ok(())
+	}
+}
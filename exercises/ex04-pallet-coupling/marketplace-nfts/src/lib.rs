@@ -5,12 +5,13 @@
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
 
+mod impl_nonfungibles;
 #[cfg(test)]
 mod tests;
 pub mod types;
 
 use codec::EncodeLike;
-use frame_support::ensure;
+use frame_support::{dispatch::DispatchResult, ensure, BoundedVec};
 use sp_runtime::traits::{AtLeast32BitUnsigned, One, Saturating};
 use types::*;
 
@@ -88,6 +89,10 @@ pub mod pallet {
 		NotOwned,
 		/// Supply must be positive
 		NoSupply,
+		/// The ID is not the next one to be assigned
+		UnavailableId,
+		/// The asset is held by several accounts
+		NoSoleOwner,
 	}
 
 	#[pallet::call]
@@ -102,16 +107,7 @@ pub mod pallet {
 
 			ensure!(supply > 0, Error::<T>::NoSupply);
 
-			let id = Self::nonce();
-			let details = UniqueAssetDetails::new(origin.clone(), metadata, supply);
-			UniqueAsset::<T>::insert(id, details);
-			Account::<T>::insert(id, origin.clone(), supply);
-			Nonce::<T>::set(id.saturating_add(T::NFTId::one()));
-
-			Self::deposit_event(Event::<T>::Created {
-				creator: origin,
-				asset_id: id,
-			});
+			Self::do_mint(origin, metadata, supply);
 
 			Ok(())
 		}
@@ -123,31 +119,7 @@ pub mod pallet {
 			ensure!(Self::unique_asset(asset_id).is_some(), Error::<T>::Unknown);
 			Self::ensure_own_some(asset_id, origin.clone())?;
 
-			let mut total_supply = 0;
-
-			UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
-
-				let mut burned_amount = 0;
-				Account::<T>::mutate(asset_id, origin.clone(), |balance| {
-					let old_balance = *balance;
-					*balance = balance.saturating_sub(amount);
-					burned_amount = old_balance - *balance;
-				});
-
-				details.supply -= burned_amount;
-				total_supply = details.supply;
-
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::<T>::Burned {
-				asset_id,
-				owner: origin,
-				total_supply,
-			});
-
-			Ok(())
+			Self::do_burn(asset_id, origin, amount)
 		}
 
 		#[pallet::weight(0)]
@@ -170,6 +142,63 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	// Create a new asset whose whole supply is held by `creator`, returning its ID.
+	fn do_mint(
+		creator: T::AccountId,
+		metadata: BoundedVec<u8, T::MaxLength>,
+		supply: u128,
+	) -> T::NFTId {
+		let id = Self::nonce();
+		let details = UniqueAssetDetails::new(creator.clone(), metadata, supply);
+		UniqueAsset::<T>::insert(id, details);
+		Account::<T>::insert(id, creator.clone(), supply);
+		Nonce::<T>::set(id.saturating_add(T::NFTId::one()));
+
+		Self::deposit_event(Event::<T>::Created {
+			creator,
+			asset_id: id,
+		});
+
+		id
+	}
+
+	// Destroy up to `amount` of `asset_id` held by `owner`.
+	fn do_burn(asset_id: T::NFTId, owner: T::AccountId, amount: u128) -> DispatchResult {
+		let mut total_supply = 0;
+
+		UniqueAsset::<T>::try_mutate(asset_id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+
+			let mut burned_amount = 0;
+			Account::<T>::mutate(asset_id, owner.clone(), |balance| {
+				let old_balance = *balance;
+				*balance = balance.saturating_sub(amount);
+				burned_amount = old_balance - *balance;
+			});
+
+			details.supply -= burned_amount;
+			total_supply = details.supply;
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T>::Burned {
+			asset_id,
+			owner,
+			total_supply,
+		});
+
+		Ok(())
+	}
+
+	/// The account whose balance of `nft_id` is its whole, non-zero, supply.
+	pub fn sole_owner(nft_id: T::NFTId) -> Option<T::AccountId> {
+		let supply = Self::unique_asset(nft_id).map(|details| details.supply).filter(|s| *s > 0)?;
+		Account::<T>::iter_prefix(nft_id)
+			.find(|(_, balance)| *balance == supply)
+			.map(|(owner, _)| owner)
+	}
+
 	fn ensure_own_some(asset_id: T::NFTId, account: T::AccountId) -> Result<(), Error<T>> {
 		let owned = Self::account(asset_id, account);
 
@@ -1,2 +1,3 @@
 pub mod mock;
+pub mod nonfungibles;
 pub mod unique_assets;
@@ -0,0 +1,176 @@
+use crate::{tests::mock::*, Error};
+use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::TokenError,
+	traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
+};
+
+fn mint(supply: u128) {
+	assert_ok!(NFTs::mint(
+		Origin::signed(ALICE),
+		"Some metadata".as_bytes().to_vec().try_into().unwrap(),
+		supply
+	));
+}
+
+mod inspect {
+	use super::*;
+
+	#[test]
+	fn sole_holder_is_owner() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), Some(ALICE));
+			assert!(<NFTs as Inspect<u64>>::can_transfer(&(), &0));
+
+			// The whole supply moved to another account.
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 5, BOB));
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), Some(BOB));
+		})
+	}
+
+	#[test]
+	fn split_asset_has_no_owner() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), None);
+			assert!(!<NFTs as Inspect<u64>>::can_transfer(&(), &0));
+		})
+	}
+
+	#[test]
+	fn unknown_asset_has_no_owner() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), None);
+			assert_eq!(<NFTs as Inspect<u64>>::collection_owner(&()), None);
+			assert!(!<NFTs as Inspect<u64>>::can_transfer(&(), &0));
+		})
+	}
+}
+
+mod create {
+	use super::*;
+
+	#[test]
+	fn is_unsupported() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<NFTs as Create<u64>>::create_collection(&(), &ALICE, &ALICE),
+				TokenError::Unsupported
+			);
+		})
+	}
+}
+
+mod mutate {
+	use super::*;
+
+	#[test]
+	fn mint_into() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<NFTs as Mutate<u64>>::mint_into(&(), &0, &BOB));
+
+			let details = NFTs::unique_asset(0).unwrap();
+			assert_eq!(details.creator(), BOB);
+			assert_eq!(details.supply, 1);
+			assert_eq!(NFTs::nonce(), 1);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), Some(BOB));
+		})
+	}
+
+	#[test]
+	fn mint_into_must_use_next_id() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<NFTs as Mutate<u64>>::mint_into(&(), &1, &BOB),
+				Error::<TestRuntime>::UnavailableId
+			);
+		})
+	}
+
+	#[test]
+	fn burn() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+			assert_ok!(<NFTs as Mutate<u64>>::burn(&(), &0, Some(&ALICE)));
+
+			assert_eq!(NFTs::unique_asset(0).unwrap().supply, 0);
+			assert_eq!(NFTs::account(0, ALICE), 0);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), None);
+		})
+	}
+
+	#[test]
+	fn burn_checks_owner() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+			assert_noop!(
+				<NFTs as Mutate<u64>>::burn(&(), &0, Some(&BOB)),
+				Error::<TestRuntime>::NotOwned
+			);
+		})
+	}
+
+	#[test]
+	fn burn_must_have_sole_owner() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+			assert_noop!(
+				<NFTs as Mutate<u64>>::burn(&(), &0, None),
+				Error::<TestRuntime>::NoSoleOwner
+			);
+		})
+	}
+
+	#[test]
+	fn burn_must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<NFTs as Mutate<u64>>::burn(&(), &0, None),
+				Error::<TestRuntime>::Unknown
+			);
+		})
+	}
+}
+
+mod transfer {
+	use super::*;
+
+	#[test]
+	fn ok() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+			assert_ok!(<NFTs as Transfer<u64>>::transfer(&(), &0, &BOB));
+
+			assert_eq!(NFTs::account(0, ALICE), 0);
+			assert_eq!(NFTs::account(0, BOB), 5);
+			assert_eq!(<NFTs as Inspect<u64>>::owner(&(), &0), Some(BOB));
+		})
+	}
+
+	#[test]
+	fn must_have_sole_owner() {
+		new_test_ext().execute_with(|| {
+			mint(5);
+			assert_ok!(NFTs::transfer(Origin::signed(ALICE), 0, 2, BOB));
+			assert_noop!(
+				<NFTs as Transfer<u64>>::transfer(&(), &0, &ALICE),
+				Error::<TestRuntime>::NoSoleOwner
+			);
+		})
+	}
+
+	#[test]
+	fn must_exist() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<NFTs as Transfer<u64>>::transfer(&(), &0, &BOB),
+				Error::<TestRuntime>::Unknown
+			);
+		})
+	}
+}